@@ -0,0 +1,111 @@
+//! # config
+//!
+//! Construction of a [`ParserBuilder`] from a TOML configuration file. Enabled by the `config`
+//! feature.
+//!
+//! Example of a configuration file:
+//!
+//! ```toml
+//! # path of the workbook, relative to the configuration file
+//! workbook = "test_parser1.xlsx"
+//! sheets = ["Sheet1", "Sheet2"]
+//! tables = ["test_table_1", "test_table_2"]
+//! ```
+
+use std::path::Path;
+
+use serde::Deserialize;
+
+use crate::{ParseError, ParserBuilder};
+
+/// Contents of the configuration file.
+#[derive(Debug, Deserialize)]
+struct Config {
+    workbook: String,
+    #[serde(default)]
+    sheets: Vec<String>,
+    #[serde(default)]
+    tables: Vec<String>,
+}
+
+impl ParserBuilder<'static> {
+    /// Constructs a parser builder from a TOML configuration file, which contains the workbook
+    /// path and the names of the sheets and tables to parse. A relative workbook path is resolved
+    /// relative to the configuration file.
+    pub fn from_config(path: impl AsRef<Path>) -> Result<Self, ConfigError> {
+        let path = path.as_ref();
+        let config: Config = toml::from_str(&std::fs::read_to_string(path)?)?;
+
+        let workbook = path
+            .parent()
+            .unwrap_or_else(|| Path::new(""))
+            .join(&config.workbook);
+        let mut builder = ParserBuilder::open(workbook)?;
+        for sheet in config.sheets {
+            builder = builder.add_sheet(sheet)?;
+        }
+        for table in config.tables {
+            builder = builder.add_table(table)?;
+        }
+        Ok(builder)
+    }
+}
+
+/// Error returned when the parser builder can't be constructed from a configuration file.
+#[derive(Debug)]
+pub enum ConfigError {
+    /// Configuration file could not be read.
+    Io(std::io::Error),
+    /// Configuration file is not valid.
+    Parse(toml::de::Error),
+    /// Workbook could not be opened.
+    Workbook(calamine::Error),
+    /// Sheet or table does not exist in the workbook.
+    InvalidName(ParseError),
+}
+
+impl std::fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConfigError::Io(err) => write!(f, "Could not read configuration file: {}", err),
+            ConfigError::Parse(err) => write!(f, "Invalid configuration file: {}", err),
+            ConfigError::Workbook(err) => write!(f, "Could not open workbook: {}", err),
+            ConfigError::InvalidName(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ConfigError::Io(err) => Some(err),
+            ConfigError::Parse(err) => Some(err),
+            ConfigError::Workbook(err) => Some(err),
+            ConfigError::InvalidName(err) => Some(err),
+        }
+    }
+}
+
+impl From<std::io::Error> for ConfigError {
+    fn from(err: std::io::Error) -> Self {
+        ConfigError::Io(err)
+    }
+}
+
+impl From<toml::de::Error> for ConfigError {
+    fn from(err: toml::de::Error) -> Self {
+        ConfigError::Parse(err)
+    }
+}
+
+impl From<calamine::Error> for ConfigError {
+    fn from(err: calamine::Error) -> Self {
+        ConfigError::Workbook(err)
+    }
+}
+
+impl From<ParseError> for ConfigError {
+    fn from(err: ParseError) -> Self {
+        ConfigError::InvalidName(err)
+    }
+}
@@ -0,0 +1,65 @@
+//! # error_serde
+//!
+//! Serialization of the [`ParseError`](crate::ParseError) members that don't implement
+//! `Serialize` and `Deserialize` themselves. Enabled by the `serde` feature.
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Cell of a `XlsxLocation` decomposed into its position and the displayed value.
+#[derive(Serialize, Deserialize)]
+struct CellRepr {
+    row: u32,
+    col: u32,
+    value: String,
+}
+
+pub(crate) mod cell {
+    use calamine::{Cell, Data};
+
+    use super::*;
+
+    pub(crate) fn serialize<S: Serializer>(
+        cell: &Option<Cell<Data>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        cell.as_ref()
+            .map(|cell| {
+                let (row, col) = cell.get_position();
+                CellRepr {
+                    row,
+                    col,
+                    value: cell.get_value().to_string(),
+                }
+            })
+            .serialize(serializer)
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Cell<Data>>, D::Error> {
+        Ok(Option::<CellRepr>::deserialize(deserializer)?
+            .map(|cell| Cell::new((cell.row, cell.col), Data::String(cell.value))))
+    }
+}
+
+pub(crate) mod source {
+    use super::*;
+
+    type Source = Option<Box<dyn std::error::Error + Send + Sync>>;
+
+    pub(crate) fn serialize<S: Serializer>(
+        source: &Source,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        source
+            .as_ref()
+            .map(|source| source.to_string())
+            .serialize(serializer)
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Source, D::Error> {
+        Ok(Option::<String>::deserialize(deserializer)?.map(Into::into))
+    }
+}
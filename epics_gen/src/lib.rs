@@ -49,6 +49,84 @@
 //! target type. See [`FromXlsxData`] trait for more details (and the macros that
 //! automatically implement it).
 //!
+//! Optional member attributes of the `FromXlsxRow` macro:
+//!
+//! - skip: `#[xlsx(skip)]`; the member is not parsed from the row, `Default::default()` is used
+//!   instead.
+//! - default: `#[xlsx(default = "<expr>")]`; the expression is used when the cell is empty (or
+//!   instead of `Default::default()` when the member is skipped).
+//!   (e.g.: `#[xlsx(default = "f64::NAN")]`)
+//! - column: `#[xlsx(column = "<header>")]`; the member is read from the column with the given
+//!   header name instead of the next column in order. Members mapped by header don't consume
+//!   columns, so the following members continue where the previous positional member ended.
+//!   Header names are only known when parsing through a [`Parser`] (or when calling
+//!   [`FromXlsxRow::from_xlsx_row_with_header`]).
+//!   `#[xlsx(column = <index>)]` reads the member from the given zero-based column index instead
+//!   (e.g.: `#[xlsx(column = 3)]`), which maps columns in a different order than the members or
+//!   skips columns in the middle of the row. Indexed members don't consume columns either.
+//! - rename: `#[xlsx(rename = "<header>")]`; sets the header name of the member's column, when it
+//!   differs from the member name (e.g.: `#[xlsx(rename = "Output Voltage [V]")]`). The column
+//!   is looked up by this name, the same as with `column`.
+//! - as_string: `#[xlsx(as_string)]`; the `String` member also accepts numeric cells, which are
+//!   converted with [`get_as_string`] (e.g. a column containing both `1.0` and `auto`).
+//! - parse_with: `#[xlsx(parse_with = "<path>")]`; converts the member with a custom function
+//!   instead of a single cell, e.g. to combine two adjacent cells. The function is called with the
+//!   remaining cells of the row, `fn(&mut Vec<XlsxData>, usize, &str) -> Result<T, ParseError>`
+//!   (cells, row number, table name). The cells it removes from the front of the vector are
+//!   consumed, the following members continue after them.
+//! - validate: `#[xlsx(validate = "<expr>")]`; predicate the converted value needs to satisfy,
+//!   otherwise [`ParseErrorKind::InvalidValue`] is returned. The value is bound to `val`
+//!   (e.g.: `#[xlsx(validate = "(0.0..=5.0).contains(&val)")]`).
+//!
+//! A `Vec<T>` member consumes all the remaining cells of the row, so it has to be the last member
+//! of the struct. Trailing empty cells (rows are padded to the table width) are not a part of the
+//! vector, empty cells between the values are converted like any other cell. An
+//! [`XlsxRowRemainder`] (`HashMap<String, XlsxData>`) member does the same, but keeps the cells
+//! keyed by their column header names, e.g. for tables with a variable number of extra columns
+//! (see [`row_remainder`]). Columns read by the members mapped by index or header name are left
+//! out.
+//! Rows with fewer cells than the positional members require return
+//! [`ParseErrorKind::ColumnCountMismatch`].
+//! With [`ParserBuilder::strict_column_order`], the table header also needs to match the names of
+//! the positional members in order, otherwise [`ParseErrorKind::ColumnOrderMismatch`] is returned.
+//! [`MockTable`] converts rows built in memory the same way, e.g. to unit test the conversion
+//! without an xlsx file.
+//!
+//! The length of an array member has to be an integer literal, since the macro needs to know
+//! how many cells it consumes:
+//!
+//! ```compile_fail
+//! use epics_gen::FromXlsxData;
+//!
+//! const CHANNELS: usize = 4;
+//!
+//! #[derive(epics_gen::FromXlsxRow)]
+//! struct Channels {
+//!     name: String,
+//!     voltages: [f64; CHANNELS],
+//! }
+//! ```
+//!
+//! [`FromXlsxFloat`] converts a single cell, so it only accepts newtypes wrapping a float (e.g.
+//! `struct Voltage(f64)`), structs with named members are converted with [`FromXlsxRow`]:
+//!
+//! ```compile_fail
+//! use epics_gen::FromXlsxData;
+//!
+//! #[derive(epics_gen::FromXlsxFloat)]
+//! struct Voltage {
+//!     val: f64,
+//! }
+//! ```
+//!
+//! A unit struct (`struct Marker;`) discards the row, but a struct with empty braces or
+//! parentheses is most likely a mistake and is rejected:
+//!
+//! ```compile_fail
+//! #[derive(epics_gen::FromXlsxRow)]
+//! struct Marker {}
+//! ```
+//!
 //! and this an example of serializing structures to PVs:
 //!
 //! ```rust
@@ -98,11 +176,175 @@
 //! Optional attributes:
 //!
 //! - subst: `#[record(subst = "<pattern>")]`; substitutes a pattern in other fields. Similar to EPICS
-//!   macro definitions.
+//!   macro definitions. Multiple members (or patterns) can be substituted in the same struct.
 //! - fmt: `#[record(fmt = "<user_defined_string>"]`; overrides other attributes and lets the user
 //!   define a custom output.
 //!   (e.g.: `#[record(fmt = r#"record(ao, "$(P):Voltage"){field(VAL, "{{}}")"#]`)
+//! - fmt_file: `#[record(fmt_file = "<path>")]`; the same as `fmt`, with the format string read
+//!   from a file at compile time. The path is relative to the crate root (`CARGO_MANIFEST_DIR`)
+//!   (e.g.: `#[record(fmt_file = "templates/waveform.db")]`).
 //! - repr: `#[record(repr = <type>)]`; convert to type before printing the value; (e.g.: `#[record(repr = u32)]`)
+//!   `#[record(repr = "display")]` and `#[record(repr = "debug")]` format the value with `Display`
+//!   or `Debug` instead.
+//! - repr_expr: `#[record(repr_expr = "<expr>")]`; the expression is printed instead of the
+//!   member value, e.g. for unit conversions (e.g.: `#[record(repr_expr = "self.val * 1000.0")]`).
+//!   Can't be used together with `repr`.
+//! - skip: `#[record(skip)]`; omits the member from the record output.
+//! - precision: `#[record(precision = <digits>)]`; number of decimal places used when printing
+//!   the value (e.g.: `#[record(precision = 2)]`)
+//! - hex/bin: `#[record(hex)]`, `#[record(bin)]`; print the value in hexadecimal (`0x1a`) or binary
+//!   (`0b11010`) notation. Combined with `repr` the value is cast first.
+//! - width/align: `#[record(width = <width>, align = "left")]`; pads the printed value with spaces
+//!   to the given width. `align` is optional and can be `left`, `right` or `center`.
+//! - no_quote: `#[record(no_quote)]`; prints the value without the surrounding double quotes
+//!   (e.g.: `field(FLNK, PP($(P)Current))`).
+//! - link_field: `#[record(link_field = "<field>", link_target = "<target>", link_modifier = "PP")]`;
+//!   prints an EPICS link field, `field(INP, "<target> PP")`. `{}` in `link_target` is replaced by
+//!   the member value, without `link_target` the member value is the target. `link_modifier` is
+//!   optional and accepts `PP`, `NPP`, `CA`, `CP`, `CPP`, `MS`, `NMS`, `MSS` and `MSI`
+//!   (e.g.: `#[record(link_field = "INP", link_target = "$(P){}.VAL", link_modifier = "CP MS")]`).
+//! - default: `#[record(default = "<value>")]`; printed instead of the value of an `Option` member
+//!   that is `None`. `Some` values are printed the same as other members
+//!   (e.g.: `#[record(field = "DRVH", default = "0")]`).
+//! - comment: `#[record(comment = "<comment>")]`; prints a `# <comment>` line before the member's
+//!   field (or before its record, when the struct has no global record). Can be repeated.
+//! - field_if: `#[record(field_if = "<expr>")]`; the member's field (or record, when the struct
+//!   has no global record) is only printed when the condition holds
+//!   (e.g.: `#[record(field_if = "!self.egu.is_empty()")]`).
+//!
+//! Optional struct attributes:
+//!
+//! - desc: `#[record(desc = "<desc>")]`; adds `field(DESC, "<desc>")` to every record generated by
+//!   the struct. The description can be at most 40 characters long and the `DESC` field can't be
+//!   defined on a member at the same time.
+//! - egu: `#[record(egu = "<egu>")]`; adds `field(EGU, "<egu>")` to every record generated by the
+//!   struct, right after `DESC`. The units can be at most 16 characters long and the `EGU` field
+//!   can't be defined on a member at the same time.
+//! - calc_expr: `#[record(calc_expr = "<expr>")]`; adds `field(CALC, "<expr>")` to every record
+//!   generated by the struct, right after `EGU` (e.g.: `#[record(calc_expr = "A+B*C")]`). The
+//!   expression can be at most 40 characters long and can only use the input variables `A`-`L`.
+//! - dtyp: `#[record(dtyp = "<dtyp>")]`; adds `field(DTYP, "<dtyp>")` to every record generated by
+//!   the struct (e.g.: `#[record(dtyp = "Raw Soft Channel")]`). The `DTYP` field can't be
+//!   defined on a member at the same time.
+//! - scan: `#[record(scan = "<scan>")]`; adds `field(SCAN, "<scan>")` to every record generated by
+//!   the struct (e.g.: `#[record(scan = "1 second")]`). The `SCAN` field can't be defined on a
+//!   member at the same time.
+//! - pini: `#[record(pini)]`; adds `field(PINI, "YES")` to every record generated by the struct.
+//!   The `PINI` field can't be defined on a member at the same time.
+//! - lolo/low/high/hihi: `#[record(lolo = <limit>, low = <limit>, high = <limit>, hihi = <limit>)]`;
+//!   adds the alarm limit fields `LOLO`, `LOW`, `HIGH` and `HIHI` to every record generated by the
+//!   struct, after `PINI`. A limit is a number or a string (e.g.: `#[record(lolo = -10.5,
+//!   hihi = "$(HIHI)")]`). Using them with a record type without alarm limits (anything but `ai`,
+//!   `ao`, `longin`, `longout`, `int64in`, `int64out`, `calc` and `calcout`) emits a warning.
+//! - mbbi_states/mbbi_values: `#[record(mbbi_states = ["Off", "On"], mbbi_values = [0, 1])]`;
+//!   adds the mbbi/mbbo state strings (`ZRST`, `ONST`, …) and values (`ZRVL`, `ONVL`, …) to every
+//!   record generated by the struct. Both lists can have at most 16 entries and must have the same
+//!   length when used together.
+//! - rec_name_expr: `#[record(rec_name_expr = "<expr>")]`; record name computed at runtime, used
+//!   instead of `rec_name` (e.g.: `#[record(rec_name_expr = r#"format!("CH{}Val", self.channel)"#)]`).
+//! - also_rec_name/also_rec_type: `#[record(also_rec_name = "<name>", also_rec_type = "<type>")]`;
+//!   appends a companion record with an empty body after the global record (e.g. a `stringin`
+//!   record used as a link). Can be repeated, names and types are paired in order.
+//! - subst_field/subst_pattern: `#[record(subst_field = "<member>", subst_pattern = "<pattern>")]`;
+//!   substitutes the pattern by the value of the named member, like `subst`, but the member is
+//!   still printed as any other member (e.g.: `#[record(rec_name = "$(P)CH$(CH_IDX):Voltage",
+//!   subst_field = "ch_idx", subst_pattern = "$(CH_IDX)")]`). Can be repeated, members and
+//!   patterns are paired in order.
+//! - fmt_sep: `#[record(fmt_sep = "<separator>")]`; separator between the record blocks of a
+//!   struct without a global record, `"\n"` by default (e.g.: `#[record(fmt_sep = "\n\n")]` adds
+//!   a blank line between records).
+//! - rec_syntax: `#[record(rec_syntax = "grecord")]`; keyword of the generated record definitions,
+//!   `record` (default) or `grecord`.
+//! - indent: `#[record(indent = 4)]`; indentation of the `field(…)` lines, a number of spaces
+//!   (2 by default) or a tab (`#[record(indent = "\t")]`).
+//! - pad_fields: `#[record(pad_fields = true)]`; pads the field names with spaces, so the values of
+//!   the `field(…)` lines are aligned to the longest field name of the struct.
+//! - as_record_fn: `#[record(as_record_fn = "<name>")]`; adds an inherent method with the given
+//!   name which returns the same string as `AsRecord::as_record`, e.g. when the struct already
+//!   has an inherent `as_record` method (e.g.: `#[record(as_record_fn = "to_db_record")]`).
+//! - impl_display: `#[record(impl_display)]`; implements `Display` for the struct, which prints the
+//!   same string as `AsRecord::as_record` (e.g. `println!("{}", record)`).
+//! - bound: `#[record(bound = "T: Debug")]`; where clause predicates of the generated impl. By
+//!   default every type parameter of a generic struct is bound by `Display + Clone`.
+//! - comment: `#[record(comment = "<comment>")]`; prints a `# <comment>` line before every record
+//!   block generated by the struct. Can be repeated.
+//! - strict: `#[record(strict)]`; every member has to have a record attribute (or `skip`),
+//!   otherwise the macro fails. Without it, unannotated members are silently omitted.
+//! - strict_type_check: `#[record(strict_type_check)]`; warns (at compile time) when a `calc` or
+//!   `calcout` record has no `CALC` field (neither `calc_expr` nor a `field = "CALC"` member), or
+//!   when a variable of `calc_expr` has no `INPx` (or constant `x`) field.
+//! - global_prefix: `#[record(global_prefix = "<prefix>")]`; prepends the prefix to every `rec_name`
+//!   of the struct (e.g.: `#[record(global_prefix = "$(P)")]`). A `rec_name` that already starts
+//!   with the prefix is an error.
+//! - global_suffix: `#[record(global_suffix = "<suffix>")]`; appends the suffix to every `rec_name`
+//!   of the struct (e.g.: `#[record(global_suffix = "-RB")]`). A `rec_name` that already ends with
+//!   the suffix is an error.
+//!
+//! A field added by a struct attribute can't be defined by a member at the same time, which would
+//! print the field twice:
+//!
+//! ```compile_fail
+//! use epics_gen::AsRecord;
+//!
+//! #[derive(AsRecord)]
+//! #[record(rec_name = "$(P)Voltage", rec_type = "ao", pini)]
+//! struct Voltage {
+//!     #[record(field = "PINI")]
+//!     pini: &'static str,
+//! }
+//! ```
+//!
+//! ```compile_fail
+//! use epics_gen::AsRecord;
+//!
+//! #[derive(AsRecord)]
+//! #[record(rec_name = "$(P)Voltage", rec_type = "ai", scan = "1 second")]
+//! struct Voltage {
+//!     #[record(field = "SCAN")]
+//!     scan: &'static str,
+//! }
+//! ```
+//!
+//! The `calc_expr` expression is checked at compile time, e.g. for unbalanced parentheses:
+//!
+//! ```compile_fail
+//! use epics_gen::AsRecord;
+//!
+//! #[derive(AsRecord)]
+//! #[record(rec_name = "$(P)Sum", rec_type = "calc", calc_expr = "(A+B*C")]
+//! struct Sum {
+//!     #[record(field = "INPA")]
+//!     inpa: &'static str,
+//! }
+//! ```
+//!
+//! In strict mode, a member without a record attribute is an error instead of being omitted:
+//!
+//! ```compile_fail
+//! use epics_gen::AsRecord;
+//!
+//! #[derive(AsRecord)]
+//! #[record(rec_name = "$(P)Voltage", rec_type = "ao", strict)]
+//! struct Voltage {
+//!     #[record(field = "VAL")]
+//!     val: f64,
+//!     channel: u8,
+//! }
+//! ```
+//!
+//! Record names (`rec_name` and `also_rec_name`, together with the global prefix and suffix) can
+//! be at most 60 characters long. Names containing macros (`$(P)`) are not checked:
+//!
+//! ```compile_fail
+//! use epics_gen::AsRecord;
+//!
+//! #[derive(AsRecord)]
+//! #[record(global_prefix = "LAB-RACK01-CRATE02:")]
+//! struct Voltage {
+//!     #[record(rec_name = "PowerSupplyChannel01:OutputVoltageSetpoint", rec_type = "ao", field = "VAL")]
+//!     val: f64,
+//! }
+//! ```
 //!
 //! ## Usage
 //!
@@ -124,6 +366,9 @@
 //! }
 //! ```
 //!
+//! In the global mode, a member without a `field` (or `fmt`) attribute prints no field, it can
+//! still be used by the struct attributes, e.g. by `rec_name_expr`.
+//!
 //! local record definition example:
 //!
 //! ```ignore
@@ -141,18 +386,49 @@
 //! }
 //! ```
 //!
+//! In the local mode, a member without a record attribute produces no record and is left out of
+//! the output. In both modes, use `#[record(strict)]` to turn such members into an error (see
+//! [Attributes](#attributes)).
+//!
+//! The macro implements the [`AsRecord`] trait. Besides `as_record`, which returns all records as
+//! a single string, the trait also provides `as_records`, which returns every record as a separate
+//! string, and `as_record_write`, which writes the records directly to any `std::io::Write`.
+//!
+//! Note that the macro implements the trait instead of an inherent `as_record` method, so the
+//! trait needs to be in scope when calling it (`use epics_gen::AsRecord;` imports both the trait
+//! and the derive macro).
+//!
+//! The derived [`AsRecord::as_record_fields`] returns the printed fields as name/value pairs, for
+//! tools that need structured access to the records instead of the formatted string.
+//!
+//! Records of multiple structures can be collected in a [`RecordFile`], checked for duplicate
+//! record names and written to a `.db` file. [`validate_db_string`] checks the database syntax of
+//! the generated records (e.g. unbalanced braces in `fmt` strings) before they are loaded by the
+//! IOC.
+//!
 //! See tests for usage examples of other attributes.
 //!
 
 use std::collections::HashMap;
+use std::io::{Read, Seek};
 
 use calamine::{Cell, Data, Reader};
+use indexmap::IndexMap;
 
 #[allow(unused_imports)]
 #[cfg(feature = "derive")]
 pub use epics_gen_macros::*;
 
-use regex::Regex;
+/// Pattern of the sheet and table names ([`ParserBuilder::add_sheets`],
+/// [`ParserBuilder::add_tables`]). Reexported from regex.
+pub use regex::Regex;
+
+#[cfg(feature = "config")]
+mod config;
+#[cfg(feature = "config")]
+pub use config::ConfigError;
+#[cfg(feature = "serde")]
+mod error_serde;
 
 // Excel workbook. Reexported from calamine.
 pub type XlsxWorkbook = calamine::Xlsx<std::io::BufReader<std::fs::File>>;
@@ -164,40 +440,154 @@ pub type XlsxData = calamine::Data;
 /// A struct that represents a row in a table/sheet. Reexported from calamine.
 pub type XlsxRow = Vec<XlsxData>;
 
+/// Cells of the columns that aren't read by other members, keyed by their header names. A
+/// [`FromXlsxRow`] struct collects them into its last member of this type.
+pub type XlsxRowRemainder = HashMap<String, XlsxData>;
+
+/// Collects the cells of `row` from `first_col` on, except the columns in `read_cols`, keyed by
+/// their names in `header`. Cells without a header name are keyed by their column letters (e.g.
+/// `AC`).
+///
+/// [`XlsxRowRemainder`] members of [`FromXlsxRow`] structs are read this way, leaving out the
+/// columns already read by the members mapped by index or header name.
+pub fn row_remainder(
+    row: &[XlsxData],
+    header: &[String],
+    first_col: usize,
+    read_cols: &[usize],
+) -> XlsxRowRemainder {
+    row.iter()
+        .enumerate()
+        .skip(first_col)
+        .filter(|(col, _)| !read_cols.contains(col))
+        .map(|(col, cell)| {
+            let name = match header.get(col) {
+                Some(name) => name.clone(),
+                None => col_to_letter(col as u32),
+            };
+            (name, cell.clone())
+        })
+        .collect()
+}
+
+/// Excel date, time or duration. Reexported from calamine.
+pub type XlsxDateTime = calamine::ExcelDateTime;
+pub use calamine::ExcelDateTimeType;
+
 /// A struct that represents an Excel Cell unit. Reexported from calamine.
 pub type XlsxCell = calamine::Cell<XlsxData>;
 pub use calamine::open_workbook;
 
+/// Excel workbook read from memory.
+pub type XlsxWorkbookMem<'a> = calamine::Xlsx<std::io::BufReader<std::io::Cursor<&'a [u8]>>>;
+
+/// Opens a workbook from an in-memory xlsx file, e.g. one embedded with `include_bytes!()`.
+pub fn open_workbook_from_bytes(bytes: &[u8]) -> Result<XlsxWorkbookMem<'_>, calamine::Error> {
+    Ok(calamine::Xlsx::new(std::io::BufReader::new(
+        std::io::Cursor::new(bytes),
+    ))?)
+}
+
 /// Builder for parsers.
 ///
 /// This is used to build parsers of excel tables. Use [`add_tables`](Self::add_tables) and
 /// [`add_sheets`](Self::add_sheets) to specify which tables it needs to parse and
 /// which sheets to find them in.
-pub struct ParserBuilder<'a> {
-    workbook: &'a mut XlsxWorkbook,
+///
+/// The builder either borrows an already opened workbook ([`new`](Self::new)) or opens and owns
+/// it ([`open`](Self::open)).
+///
+/// The builder is generic over the workbook reader `R`, so both file-backed ([`XlsxWorkbook`])
+/// and in-memory ([`XlsxWorkbookMem`]) workbooks can be parsed.
+///
+/// With the `config` feature, the builder can also be constructed from a TOML configuration file
+/// (`ParserBuilder::from_config`).
+pub struct ParserBuilder<'a, R = std::io::BufReader<std::fs::File>> {
+    workbook: WorkbookRef<'a, R>,
     sheets: Vec<Entry>,
     tables: Vec<Entry>,
+    strict_column_order: bool,
 }
 
 enum Entry {
     String(String),
-    Regex(regex::Regex),
+    Regex(Regex),
+}
+
+/// Workbook either opened by the parser itself or borrowed from the caller.
+enum WorkbookRef<'a, R> {
+    Owned(Box<calamine::Xlsx<R>>),
+    Borrowed(&'a mut calamine::Xlsx<R>),
+}
+
+impl<R> std::ops::Deref for WorkbookRef<'_, R> {
+    type Target = calamine::Xlsx<R>;
+
+    fn deref(&self) -> &Self::Target {
+        match self {
+            WorkbookRef::Owned(workbook) => workbook,
+            WorkbookRef::Borrowed(workbook) => workbook,
+        }
+    }
+}
+
+impl<R> std::ops::DerefMut for WorkbookRef<'_, R> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        match self {
+            WorkbookRef::Owned(workbook) => workbook,
+            WorkbookRef::Borrowed(workbook) => workbook,
+        }
+    }
 }
 
 impl<'a> ParserBuilder<'a> {
+    /// Opens the workbook at `path` and constructs a new parser builder that owns it.
+    pub fn open(path: impl AsRef<std::path::Path>) -> Result<Self, calamine::Error> {
+        let mut workbook: XlsxWorkbook = open_workbook(path)?;
+        workbook.load_tables()?;
+        Ok(Self {
+            workbook: WorkbookRef::Owned(Box::new(workbook)),
+            sheets: Vec::new(),
+            tables: Vec::new(),
+            strict_column_order: false,
+        })
+    }
+}
+
+impl<'a, R: Read + Seek> ParserBuilder<'a, R> {
     /// Construct new parser builder.
-    pub fn new(workbook: &'a mut calamine::Xlsx<std::io::BufReader<std::fs::File>>) -> Self {
+    pub fn new(workbook: &'a mut calamine::Xlsx<R>) -> Self {
         workbook
             .load_tables()
             .expect("Could not load workbook tables!");
         Self {
-            workbook,
+            workbook: WorkbookRef::Borrowed(workbook),
             sheets: Vec::new(),
             tables: Vec::new(),
+            strict_column_order: false,
         }
     }
 
+    /// Names of all the sheets in the workbook, e.g. to let the user pick the sheets to parse.
+    pub fn sheet_names(&self) -> Vec<String> {
+        self.workbook.sheet_names()
+    }
+
+    /// Names of the tables in the given sheet of the workbook. The result is empty if the sheet
+    /// doesn't exist.
+    pub fn table_names(&self, sheet: &str) -> Vec<String> {
+        self.workbook
+            .table_names_in_sheet(sheet)
+            .into_iter()
+            .cloned()
+            .collect()
+    }
+
     /// Adds single sheet to parser.
+    ///
+    /// Returns [`ParseErrorKind::InvalidSheetName`] if the workbook has no sheet with this name,
+    /// so the builder calls can be chained with `?`:
+    /// `ParserBuilder::new(&mut wb).add_sheet("Sheet1")?.add_table("t1")?.build()`.
     pub fn add_sheet(mut self, sheet: impl Into<String>) -> Result<Self, ParseError> {
         let sheet: String = sheet.into();
         let sheet_exists = self
@@ -214,6 +604,8 @@ impl<'a> ParserBuilder<'a> {
     }
 
     /// Adds a pattern which is expanded to matched sheet names in the workbook.
+    ///
+    /// Returns [`ParseErrorKind::InvalidSheetName`] if the pattern matches no sheet.
     pub fn add_sheets(mut self, sheet_pattern: Regex) -> Result<Self, ParseError> {
         let sheet_exists = self
             .workbook
@@ -229,13 +621,16 @@ impl<'a> ParserBuilder<'a> {
     }
 
     /// Adds single table to parser.
+    ///
+    /// Returns [`ParseErrorKind::InvalidTableName`] if none of the already added sheets contain
+    /// the table, so sheets need to be added first.
     pub fn add_table(mut self, table: impl Into<String>) -> Result<Self, ParseError> {
         let table: String = table.into();
         let table_exists = self
-            .workbook
-            .table_names()
-            .into_iter()
-            .any(|existing_table| existing_table.as_str() == table.clone());
+            .get_added_sheets()
+            .iter()
+            .flat_map(|sheet| self.workbook.table_names_in_sheet(sheet))
+            .any(|existing_table| existing_table.as_str() == table);
         if table_exists {
             self.tables.push(Entry::String(table));
             Ok(self)
@@ -245,11 +640,14 @@ impl<'a> ParserBuilder<'a> {
     }
 
     /// Adds a pattern which is expanded to matched table names in the workbook.
+    ///
+    /// Returns [`ParseErrorKind::InvalidTableName`] if the pattern matches no table in the already
+    /// added sheets.
     pub fn add_tables(mut self, table_pattern: Regex) -> Result<Self, ParseError> {
         let table_exists = self
-            .workbook
-            .table_names()
-            .into_iter()
+            .get_added_sheets()
+            .iter()
+            .flat_map(|sheet| self.workbook.table_names_in_sheet(sheet))
             .any(|table| table_pattern.is_match(table));
         if table_exists {
             self.tables.push(Entry::Regex(table_pattern));
@@ -259,6 +657,128 @@ impl<'a> ParserBuilder<'a> {
         }
     }
 
+    /// Adds single sheet together with its tables to parser.
+    ///
+    /// Returns [`ParseErrorKind::InvalidSheetName`] if the workbook has no sheet with this name and
+    /// [`ParseErrorKind::InvalidTableName`] if any of the tables is not in the sheet.
+    pub fn add_sheet_with_tables(
+        self,
+        sheet: impl Into<String>,
+        tables: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Result<Self, ParseError> {
+        let sheet: String = sheet.into();
+        let mut builder = self.add_sheet(sheet.clone())?;
+        for table in tables {
+            let table: String = table.into();
+            let table_exists = builder
+                .workbook
+                .table_names_in_sheet(&sheet)
+                .into_iter()
+                .any(|existing_table| existing_table.as_str() == table);
+            if !table_exists {
+                return Err(ParseError::invalid_table(table));
+            }
+            builder.tables.push(Entry::String(table));
+        }
+        Ok(builder)
+    }
+
+    /// Checks the tables matched by the added entries against the target type `O`, without
+    /// building the parser and parsing any rows. A table with fewer columns than
+    /// [`FromXlsxRow::COLUMN_COUNT`] is reported as [`ParseErrorKind::ColumnCountMismatch`] and,
+    /// with [`strict_column_order`](Self::strict_column_order), a table header not matching
+    /// [`FromXlsxRow::COLUMN_NAMES`] as [`ParseErrorKind::ColumnOrderMismatch`]. All the invalid
+    /// tables are returned at once.
+    pub fn validate<O: FromXlsxRow>(&mut self) -> Result<(), Vec<ParseError>> {
+        let mut errors: Vec<ParseError> = Vec::new();
+        for sheet_name in self.matched_sheets() {
+            for table_name in self.get_valid_tables(&sheet_name) {
+                let table = match self.workbook.table_by_name(&table_name) {
+                    Ok(table) => table,
+                    Err(err) => {
+                        errors.push(ParseError::invalid_table(table_name).with_source(err));
+                        continue;
+                    }
+                };
+                let columns = table.columns();
+                if columns.len() < O::COLUMN_COUNT {
+                    errors.push(ParseError::new_in_table(
+                        ParseErrorKind::ColumnCountMismatch,
+                        Cell::new((0, columns.len() as u32), Data::Empty),
+                        table_name,
+                    ));
+                } else if self.strict_column_order {
+                    if let Err(err) = check_column_order::<O>(columns) {
+                        errors.push(err);
+                    }
+                }
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Enables the check of the table header against the member names of the target type. In
+    /// strict mode, the headers of the positional columns (see [`FromXlsxRow::COLUMN_NAMES`])
+    /// need to match the member names in order, otherwise parsing the table returns
+    /// [`ParseErrorKind::ColumnOrderMismatch`]. Names are compared ignoring case and
+    /// non-alphanumeric characters, so the member `row_id` matches the header `Row ID`.
+    pub fn strict_column_order(mut self, strict: bool) -> Self {
+        self.strict_column_order = strict;
+        self
+    }
+
+    /// Returns the names of the workbook sheets matched by the added sheet entries, in the order
+    /// they are parsed. Useful to log or display the selected sheets before building the parser.
+    pub fn matched_sheets(&self) -> Vec<String> {
+        let mut res: Vec<String> = Vec::new();
+        for sheet_name in self.get_added_sheets() {
+            if !res.contains(&sheet_name) {
+                res.push(sheet_name);
+            }
+        }
+        res
+    }
+
+    /// Returns the names of the tables in the given sheet matched by the added table entries, in
+    /// the order they are parsed. Empty if the sheet is not matched by any sheet entry.
+    pub fn matched_tables(&self, sheet: &str) -> Vec<String> {
+        if self
+            .get_added_sheets()
+            .iter()
+            .any(|sheet_name| sheet_name == sheet)
+        {
+            self.get_valid_tables(sheet)
+        } else {
+            Vec::new()
+        }
+    }
+
+    /// Returns names of all the workbook sheets that match the added sheet entries.
+    fn get_added_sheets(&self) -> Vec<String> {
+        let sheet_names = self.workbook.sheet_names();
+        let mut res: Vec<String> = Vec::new();
+        self.sheets
+            .iter()
+            .for_each(|sheet_entry| match sheet_entry {
+                Entry::String(s) => {
+                    if sheet_names.contains(s) {
+                        res.push(s.to_string());
+                    }
+                }
+                Entry::Regex(r) => sheet_names.iter().for_each(|sheet_name| {
+                    if r.is_match(sheet_name) {
+                        res.push(sheet_name.to_string());
+                    }
+                }),
+            });
+        res
+    }
+
     fn get_valid_tables(&self, sheet_name: &str) -> Vec<String> {
         let table_names_in_sheet = self.workbook.table_names_in_sheet(sheet_name);
         let mut res: Vec<String> = Vec::new();
@@ -280,61 +800,102 @@ impl<'a> ParserBuilder<'a> {
     }
 
     // Builds the parser.
-    pub fn build(self) -> Parser<'a> {
-        let mut sheets: HashMap<String, Vec<String>> = HashMap::new();
-        let sheet_names = self.workbook.sheet_names();
-        self.sheets
-            .iter()
-            .for_each(|sheet_entry| match sheet_entry {
-                Entry::String(s) => {
-                    if sheet_names.contains(s) {
-                        sheets.insert(s.to_string(), self.get_valid_tables(s));
-                    }
-                }
-                Entry::Regex(r) => self
-                    .workbook
-                    .sheet_names()
-                    .into_iter()
-                    .for_each(|sheet_name| {
-                        if r.is_match(&sheet_name) {
-                            sheets
-                                .insert(sheet_name.to_string(), self.get_valid_tables(&sheet_name));
-                        }
-                    }),
-            });
+    pub fn build(self) -> Parser<'a, R> {
+        let mut sheets: IndexMap<String, Vec<String>> = IndexMap::new();
+        self.get_added_sheets().into_iter().for_each(|sheet_name| {
+            let tables = self.get_valid_tables(&sheet_name);
+            sheets.insert(sheet_name, tables);
+        });
 
         Parser {
             workbook: self.workbook,
             sheets,
+            strict_column_order: self.strict_column_order,
         }
     }
 }
 
 /// Parser structure. It's only purpose is to call [`parse`](Self::parse) and convert tables into a
 /// vector of user defined structs.
-pub struct Parser<'a> {
-    workbook: &'a mut XlsxWorkbook,
-    sheets: HashMap<String, Vec<String>>,
+pub struct Parser<'a, R = std::io::BufReader<std::fs::File>> {
+    workbook: WorkbookRef<'a, R>,
+    sheets: IndexMap<String, Vec<String>>,
+    strict_column_order: bool,
+}
+
+/// Parsed row together with the name of the table it was parsed from. Returned by
+/// [`Parser::parse_with_table_names`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct WithTableName<T> {
+    pub inner: T,
+    pub table: String,
+}
+
+impl<T> WithTableName<T> {
+    /// Returns the parsed row, discarding the table name.
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
 }
 
-impl<'a> Parser<'a> {
+impl<T> std::ops::Deref for WithTableName<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+/// Optional header row (the column names) and the conversion result of every row of a table.
+type TableRows<O> = (Option<XlsxRow>, Vec<Result<O, ParseError>>);
+
+impl<R: Read + Seek> Parser<'_, R> {
     fn parse_by_rows<O: FromXlsxRow>(&mut self, table_name: String) -> Result<Vec<O>, ParseError> {
-        let mut res = Vec::new();
-        let table = self.workbook.table_by_name(&table_name).map_err(|_| {
+        let (_, rows) = self.parse_rows(table_name, false)?;
+        rows.into_iter().collect()
+    }
+
+    /// Converts every row of the table, keeping the result of each row. If `with_header` is set,
+    /// the column names of the table are returned as the header row. Calamine keeps them out of
+    /// the table rows, so no row is skipped.
+    fn parse_rows<O: FromXlsxRow>(
+        &mut self,
+        table_name: String,
+        with_header: bool,
+    ) -> Result<TableRows<O>, ParseError> {
+        let table = self.workbook.table_by_name(&table_name).map_err(|err| {
             ParseError::new_in_table(
                 ParseErrorKind::InvalidTableName,
                 Cell::new((0, 0), Data::Empty),
                 table_name,
             )
+            .with_source(err)
         })?;
+        #[cfg(feature = "logging")]
+        log::debug!(
+            "Parsing table '{}' in sheet '{}'",
+            table.name(),
+            table.sheet_name()
+        );
 
-        let rows = table.data().rows();
-
-        for (i, row) in rows.enumerate() {
-            res.push(O::from_xlsx_row(row.into(), i, table.name())?);
+        if self.strict_column_order {
+            check_column_order::<O>(table.columns())?;
         }
 
-        Ok(res)
+        let header = with_header.then(|| {
+            table
+                .columns()
+                .iter()
+                .map(|column| Data::String(column.clone()))
+                .collect()
+        });
+
+        let rows = table.data().rows().enumerate();
+        let res: Vec<Result<O, ParseError>> = convert_rows(rows, table.columns(), table.name());
+        #[cfg(feature = "logging")]
+        log::debug!("Parsed {} rows from table '{}'", res.len(), table.name());
+
+        Ok((header, res))
     }
 
     /// Parse tables to struct.
@@ -347,22 +908,228 @@ impl<'a> Parser<'a> {
         }
         Ok(res)
     }
+
+    /// Parse tables to struct and map every parsed row into zero or more output values.
+    pub fn flat_map_parse<O, T, F>(mut self, mut f: F) -> Result<Vec<T>, ParseError>
+    where
+        O: FromXlsxRow,
+        F: FnMut(O) -> Vec<T>,
+    {
+        let mut res: Vec<T> = Vec::new();
+        for (_, tables) in self.sheets.clone().into_iter() {
+            for table in tables {
+                let (_, rows) = self.parse_rows::<O>(table, false)?;
+                for row in rows {
+                    res.extend(f(row?));
+                }
+            }
+        }
+        Ok(res)
+    }
+
+    /// Parse tables to struct, keeping the name of the table every row was parsed from.
+    pub fn parse_with_table_names<O: FromXlsxRow>(
+        mut self,
+    ) -> Result<Vec<WithTableName<O>>, ParseError> {
+        let mut res: Vec<WithTableName<O>> = Vec::new();
+        for (_, tables) in self.sheets.clone().into_iter() {
+            for table in tables {
+                let rows: Vec<O> = self.parse_by_rows(table.clone())?;
+                res.extend(rows.into_iter().map(|inner| WithTableName {
+                    inner,
+                    table: table.clone(),
+                }));
+            }
+        }
+        Ok(res)
+    }
+
+    /// Parse only the tables of the given sheet to struct. Returns
+    /// [`ParseErrorKind::InvalidTableName`] if the sheet was not added to the parser.
+    pub fn parse_sheet<O: FromXlsxRow>(mut self, sheet_name: &str) -> Result<Vec<O>, ParseError> {
+        let tables = self.sheets.get(sheet_name).cloned().ok_or_else(|| {
+            ParseError::new_in_sheet(
+                ParseErrorKind::InvalidTableName,
+                Cell::new((0, 0), Data::Empty),
+                sheet_name,
+            )
+        })?;
+        let mut res: Vec<O> = Vec::new();
+        for table in tables {
+            res.extend(self.parse_by_rows(table)?);
+        }
+        Ok(res)
+    }
+
+    /// Parse tables to struct, without stopping on the first error. Successfully converted rows
+    /// are returned in the first vector and errors of all the other rows in the second one.
+    pub fn parse_all<O: FromXlsxRow>(mut self) -> (Vec<O>, Vec<ParseError>) {
+        let mut res: Vec<O> = Vec::new();
+        let mut errors: Vec<ParseError> = Vec::new();
+        for (_, tables) in self.sheets.clone().into_iter() {
+            for table in tables {
+                match self.parse_rows(table, false) {
+                    Ok((_, rows)) => rows.into_iter().for_each(|row| match row {
+                        Ok(row) => res.push(row),
+                        Err(err) => errors.push(err),
+                    }),
+                    Err(err) => errors.push(err),
+                }
+            }
+        }
+        (res, errors)
+    }
+
+    /// Parse tables to struct, keeping the header row (the column names) of the first table, which
+    /// is returned together with the parsed rows of all the tables.
+    pub fn parse_with_header<O: FromXlsxRow>(mut self) -> Result<(XlsxRow, Vec<O>), ParseError> {
+        let mut header: Option<XlsxRow> = None;
+        let mut res: Vec<O> = Vec::new();
+        for (_, tables) in self.sheets.clone().into_iter() {
+            for table in tables {
+                let (table_header, rows) = self.parse_rows(table, true)?;
+                if header.is_none() {
+                    header = table_header;
+                }
+                for row in rows {
+                    res.push(row?);
+                }
+            }
+        }
+        Ok((header.unwrap_or_default(), res))
+    }
+}
+
+/// Converts the numbered rows of a table. Rows with fewer cells than [`FromXlsxRow::COLUMN_COUNT`]
+/// are reported as [`ParseErrorKind::ColumnCountMismatch`].
+fn convert_rows<'r, O: FromXlsxRow>(
+    rows: impl Iterator<Item = (usize, &'r [Data])>,
+    header: &[String],
+    table_name: &str,
+) -> Vec<Result<O, ParseError>> {
+    rows.map(|(i, row)| {
+        if row.len() < O::COLUMN_COUNT {
+            return Err(ParseError::new_in_table(
+                ParseErrorKind::ColumnCountMismatch,
+                Cell::new((i as u32, row.len() as u32), Data::Empty),
+                table_name,
+            ));
+        }
+        O::from_xlsx_row_with_header(row.into(), header, i, table_name)
+    })
+    .collect()
+}
+
+/// Table built in memory, used to unit test [`FromXlsxRow`] implementations without an xlsx
+/// file. The rows are converted the same way as the rows of a workbook table parsed by a
+/// [`Parser`], with the header names available to members mapped by header.
+///
+/// ```rust
+/// use epics_gen::FromXlsxData;
+///
+/// #[derive(epics_gen::FromXlsxRow, Debug, PartialEq)]
+/// struct Channel {
+///     name: String,
+///     gain: f64,
+/// }
+///
+/// let table = epics_gen::MockTable::new("channels")
+///     .header(["Name", "Gain"])
+///     .row(["CH1".into(), 1.5.into()]);
+/// let parsed: Vec<Channel> = table.parse().unwrap();
+/// assert_eq!(parsed[0].gain, 1.5);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MockTable {
+    name: String,
+    header: Vec<String>,
+    rows: Vec<XlsxRow>,
+}
+
+impl MockTable {
+    /// Constructs an empty table, `name` is reported in the parse errors.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Sets the header names of the table columns.
+    pub fn header(mut self, header: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.header = header.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Appends a row of cells (e.g. `["PS1".into(), 1.5.into(), XlsxData::Empty]`).
+    pub fn row(mut self, row: impl IntoIterator<Item = XlsxData>) -> Self {
+        self.rows.push(row.into_iter().collect());
+        self
+    }
+
+    /// Converts all the rows, stopping on the first error.
+    pub fn parse<O: FromXlsxRow>(&self) -> Result<Vec<O>, ParseError> {
+        self.parse_all_rows().into_iter().collect()
+    }
+
+    /// Converts all the rows, keeping the result of each row.
+    pub fn parse_all_rows<O: FromXlsxRow>(&self) -> Vec<Result<O, ParseError>> {
+        let rows = self.rows.iter().map(Vec::as_slice).enumerate();
+        convert_rows(rows, &self.header, &self.name)
+    }
+}
+
+/// Checks that the table header matches [`FromXlsxRow::COLUMN_NAMES`] in order, ignoring case
+/// and non-alphanumeric characters. The error refers to the header cell and the expected column.
+fn check_column_order<O: FromXlsxRow>(header: &[String]) -> Result<(), ParseError> {
+    fn normalize(name: &str) -> String {
+        name.chars()
+            .filter(|c| c.is_alphanumeric())
+            .flat_map(char::to_lowercase)
+            .collect()
+    }
+
+    for (col, name) in O::COLUMN_NAMES.iter().enumerate() {
+        let column = header.get(col);
+        if column.map(|column| normalize(column)) != Some(normalize(name)) {
+            let value = column.map_or(Data::Empty, |column| Data::String(column.clone()));
+            return Err(ParseError::new_in_context(
+                ParseErrorKind::ColumnOrderMismatch,
+                Cell::new((0, col as u32), value),
+                XlsxContext::column(*name),
+            ));
+        }
+    }
+    Ok(())
 }
 
 /// The `ParseError` enum is a collection of all possible reasons
 /// a value could not be parsed.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ParseErrorKind {
     InvalidValue,
     ValueMissing,
     InvalidTableName,
     InvalidSheetName,
+    InvalidColumnName,
+    /// The workbook could not be read, see [`source`](std::error::Error::source) for the cause.
+    Workbook,
+    /// The row has fewer cells than the target type requires.
+    ColumnCountMismatch,
+    /// The table header doesn't match the member names of the target type, see
+    /// [`ParserBuilder::strict_column_order`].
+    ColumnOrderMismatch,
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ParseError {
     kind: ParseErrorKind,
     location: Option<XlsxLocation>,
+    /// Serialized as its message, a deserialized error keeps only the message of the source
+    #[cfg_attr(feature = "serde", serde(with = "error_serde::source"))]
+    source: Option<Box<dyn std::error::Error + Send + Sync>>,
 }
 
 impl std::fmt::Display for ParseError {
@@ -396,6 +1163,38 @@ impl std::fmt::Display for ParseError {
                     write!(f, "Invalid sheet name.")
                 }
             }
+            ParseErrorKind::InvalidColumnName => {
+                if let Some(location) = &self.location {
+                    write!(f, "Invalid column name, {}", location)
+                } else {
+                    write!(f, "Invalid column name.")
+                }
+            }
+            ParseErrorKind::ColumnCountMismatch => {
+                if let Some(location) = &self.location {
+                    write!(f, "Row has too few columns, {}", location)
+                } else {
+                    write!(f, "Row has too few columns.")
+                }
+            }
+            ParseErrorKind::ColumnOrderMismatch => {
+                if let Some(location) = &self.location {
+                    write!(
+                        f,
+                        "Column header does not match the member name, {}",
+                        location
+                    )
+                } else {
+                    write!(f, "Column header does not match the member name.")
+                }
+            }
+            ParseErrorKind::Workbook => {
+                if let Some(location) = &self.location {
+                    write!(f, "Could not read workbook, {}", location)
+                } else {
+                    write!(f, "Could not read workbook.")
+                }
+            }
         }
     }
 }
@@ -409,41 +1208,42 @@ impl ParseError {
         Self {
             kind,
             location: None,
+            source: None,
         }
     }
-    pub fn new_in_table(
-        kind: ParseErrorKind,
-        cell: Cell<Data>,
-        table_name: impl Into<String>,
-    ) -> ParseError {
+    /// Constructs an error of the cell in the given sheet, table or column.
+    pub fn new_in_context(kind: ParseErrorKind, cell: Cell<Data>, context: XlsxContext) -> Self {
         Self {
             kind,
             location: Some(XlsxLocation {
                 cell: Some(cell),
-                context: Context::Table(table_name.into()),
+                context,
             }),
+            source: None,
         }
     }
+    pub fn new_in_table(
+        kind: ParseErrorKind,
+        cell: Cell<Data>,
+        table_name: impl Into<String>,
+    ) -> ParseError {
+        Self::new_in_context(kind, cell, XlsxContext::table(table_name))
+    }
     pub fn new_in_sheet(
         kind: ParseErrorKind,
         cell: Cell<Data>,
         sheet_name: impl Into<String>,
     ) -> ParseError {
-        Self {
-            kind,
-            location: Some(XlsxLocation {
-                cell: Some(cell),
-                context: Context::Table(sheet_name.into()),
-            }),
-        }
+        Self::new_in_context(kind, cell, XlsxContext::sheet(sheet_name))
     }
     pub fn invalid_sheet(sheet_name: impl Into<String>) -> Self {
         Self {
             kind: ParseErrorKind::InvalidSheetName,
             location: Some(XlsxLocation {
                 cell: None,
-                context: Context::Sheet(sheet_name.into()),
+                context: XlsxContext::sheet(sheet_name),
             }),
+            source: None,
         }
     }
     pub fn invalid_table(table_name: impl Into<String>) -> Self {
@@ -451,18 +1251,58 @@ impl ParseError {
             kind: ParseErrorKind::InvalidTableName,
             location: Some(XlsxLocation {
                 cell: None,
-                context: Context::Table(table_name.into()),
+                context: XlsxContext::table(table_name),
             }),
+            source: None,
         }
     }
+    /// Attaches the underlying cause of the error.
+    pub fn with_source(
+        mut self,
+        source: impl Into<Box<dyn std::error::Error + Send + Sync>>,
+    ) -> Self {
+        self.source = Some(source.into());
+        self
+    }
+    pub fn invalid_column(column_name: impl Into<String>) -> Self {
+        Self {
+            kind: ParseErrorKind::InvalidColumnName,
+            location: Some(XlsxLocation {
+                cell: None,
+                context: XlsxContext::column(column_name),
+            }),
+            source: None,
+        }
+    }
+}
+
+impl std::error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.source
+            .as_deref()
+            .map(|source| source as &(dyn std::error::Error + 'static))
+    }
+}
+
+impl From<calamine::XlsxError> for ParseError {
+    fn from(err: calamine::XlsxError) -> Self {
+        ParseError::new(ParseErrorKind::Workbook).with_source(err)
+    }
 }
 
-impl std::error::Error for ParseError {}
+impl From<calamine::Error> for ParseError {
+    fn from(err: calamine::Error) -> Self {
+        ParseError::new(ParseErrorKind::Workbook).with_source(err)
+    }
+}
 
 /// `Location` represents a location in a xslx spreadsheet or table (depending on the context)
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 struct XlsxLocation {
-    context: Context, //TODO: This could maybe be replaced with a simple string.
+    context: XlsxContext,
+    /// Serialized as `row`, `col` and the displayed `value`, which is deserialized as a string
+    #[cfg_attr(feature = "serde", serde(with = "error_serde::cell"))]
     cell: Option<Cell<Data>>,
 }
 
@@ -474,8 +1314,8 @@ impl std::fmt::Display for XlsxLocation {
                 f,
                 "{}, Row: {}, Col: {}, Value: {} ",
                 self.context,
-                row,
-                col,
+                row + 1,
+                col_to_letter(col),
                 cell.get_value()
             )
         } else {
@@ -484,19 +1324,98 @@ impl std::fmt::Display for XlsxLocation {
     }
 }
 
-//TODO: Decide if this is needed, or if it can be replaced with a simple String
-#[allow(dead_code)]
-#[derive(Debug)]
-enum Context {
-    Sheet(String),
-    Table(String),
+/// Converts a zero-based column index to the spreadsheet column letters (0 -> A, 25 -> Z,
+/// 26 -> AA, ...).
+fn col_to_letter(col: u32) -> String {
+    let mut letters = Vec::new();
+    let mut col = col as u64 + 1;
+    while col > 0 {
+        let rem = ((col - 1) % 26) as u8;
+        letters.push((b'A' + rem) as char);
+        col = (col - 1) / 26;
+    }
+    letters.iter().rev().collect()
+}
+
+/// Returns the cell content as a string, coercing numeric (and other non-empty) cells instead of
+/// returning `None` like [`DataType::get_string`]. Integral floats are printed without the
+/// decimal point (`1.0` -> `"1"`). Returns `None` only for empty cells.
+///
+/// `String` members of [`FromXlsxRow`] structs are read this way with `#[xlsx(as_string)]`.
+pub fn get_as_string(data: &XlsxData) -> Option<String> {
+    if data.is_empty() {
+        return None;
+    }
+    data.get_string()
+        .map(String::from)
+        .or_else(|| data.get_float().map(|f| f.to_string()))
+        .or_else(|| data.get_int().map(|i| i.to_string()))
+        .or_else(|| Some(data.to_string()))
+}
+
+/// Part of the workbook an error occurred in.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct XlsxContext {
+    kind: ContextKind,
+    name: String,
+}
+
+impl XlsxContext {
+    /// Context of a sheet with the given name.
+    pub fn sheet(name: impl Into<String>) -> Self {
+        Self {
+            kind: ContextKind::Sheet,
+            name: name.into(),
+        }
+    }
+
+    /// Context of a table with the given name.
+    pub fn table(name: impl Into<String>) -> Self {
+        Self {
+            kind: ContextKind::Table,
+            name: name.into(),
+        }
+    }
+
+    /// Context of a column with the given header name.
+    pub fn column(name: impl Into<String>) -> Self {
+        Self {
+            kind: ContextKind::Column,
+            name: name.into(),
+        }
+    }
+
+    pub fn kind(&self) -> ContextKind {
+        self.kind
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl std::fmt::Display for XlsxContext {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.kind, self.name)
+    }
+}
+
+/// Kind of the [`XlsxContext`], displayed as the prefix of the context name.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ContextKind {
+    Sheet,
+    Table,
+    Column,
 }
 
-impl std::fmt::Display for Context {
+impl std::fmt::Display for ContextKind {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            Context::Sheet(s) => write!(f, "Sheet: {}", s),
-            Context::Table(s) => write!(f, "Table: {}", s),
+            ContextKind::Sheet => write!(f, "Sheet"),
+            ContextKind::Table => write!(f, "Table"),
+            ContextKind::Column => write!(f, "Column"),
         }
     }
 }
@@ -512,13 +1431,564 @@ pub trait FromXlsxRow
 where
     Self: Sized,
 {
+    /// Number of cells a row needs to have to be converted. Rows with fewer cells are reported
+    /// as [`ParseErrorKind::ColumnCountMismatch`] by the [`Parser`].
+    const COLUMN_COUNT: usize;
+
+    /// Expected header names of the first columns, checked by the [`Parser`] in strict mode (see
+    /// [`ParserBuilder::strict_column_order`]). The derive macro lists the names of the named
+    /// members read by position, up to the first array or `Vec` member.
+    const COLUMN_NAMES: &'static [&'static str] = &[];
+
     fn from_xlsx_row(
         row: Vec<calamine::Data>,
         row_num: usize,
         table_name: &str,
     ) -> std::result::Result<Self, ParseError>;
+
+    /// Converts a row, with the table header (column names) available. This enables mapping
+    /// members to columns by their header name (see `#[xlsx(column = "<header>")]`).
+    fn from_xlsx_row_with_header(
+        row: Vec<calamine::Data>,
+        header: &[String],
+        row_num: usize,
+        table_name: &str,
+    ) -> std::result::Result<Self, ParseError> {
+        let _ = header;
+        Self::from_xlsx_row(row, row_num, table_name)
+    }
+}
+
+/// Converts the cell at `col` of a tuple row.
+fn tuple_element<T: FromXlsxData<Error = ParseErrorKind>>(
+    row: &[XlsxData],
+    col: usize,
+    row_num: usize,
+    table_name: &str,
+) -> Result<T, ParseError> {
+    let cell = row.get(col).cloned().unwrap_or(XlsxData::Empty);
+    T::from_xlsx_data(cell.clone()).map_err(|kind| {
+        ParseError::new_in_table(
+            kind,
+            XlsxCell::new((row_num as u32, col as u32), cell),
+            table_name,
+        )
+    })
+}
+
+macro_rules! impl_from_xlsx_row_for_tuple {
+    ($count:expr; $($ty:ident $col:tt),*) => {
+        /// Converts the first cells of the row, one cell per tuple element.
+        impl<$($ty: FromXlsxData<Error = ParseErrorKind>),*> FromXlsxRow for ($($ty,)*) {
+            const COLUMN_COUNT: usize = $count;
+
+            fn from_xlsx_row(
+                row: Vec<calamine::Data>,
+                row_num: usize,
+                table_name: &str,
+            ) -> std::result::Result<Self, ParseError> {
+                if row.len() < Self::COLUMN_COUNT {
+                    return Err(ParseError::new_in_table(
+                        ParseErrorKind::ColumnCountMismatch,
+                        XlsxCell::new((row_num as u32, row.len() as u32), XlsxData::Empty),
+                        table_name,
+                    ));
+                }
+                Ok(($(tuple_element::<$ty>(&row, $col, row_num, table_name)?,)*))
+            }
+        }
+    };
+}
+
+impl_from_xlsx_row_for_tuple!(2; A 0, B 1);
+impl_from_xlsx_row_for_tuple!(3; A 0, B 1, C 2);
+impl_from_xlsx_row_for_tuple!(4; A 0, B 1, C 2, D 3);
+impl_from_xlsx_row_for_tuple!(5; A 0, B 1, C 2, D 3, E 4);
+impl_from_xlsx_row_for_tuple!(6; A 0, B 1, C 2, D 3, E 4, F 5);
+
+/// Interface that supports serializing a structure into EPICS records. This should be implemented
+/// from a derive macro [AsRecord](epics_gen_macros::AsRecord)!
+///
+/// Since all record-bearing types share this trait, they can be collected into heterogeneous
+/// collections, e.g. `Vec<Box<dyn AsRecord>>`.
+pub trait AsRecord {
+    /// Returns all records of the structure as a single string.
+    fn as_record(&self) -> String;
+
+    /// Returns every record of the structure as a separate string.
+    fn as_records(&self) -> Vec<String> {
+        vec![self.as_record()]
+    }
+
+    /// Writes all records of the structure directly to a writer, without allocating the
+    /// intermediate string where possible.
+    fn as_record_write<W: std::io::Write>(&self, w: &mut W) -> std::io::Result<()>
+    where
+        Self: Sized,
+    {
+        w.write_all(self.as_record().as_bytes())
+    }
+
+    /// Returns the name and the value of every field of the records, in the order they are
+    /// printed by [`as_record`](Self::as_record). Values are formatted the same way, but without
+    /// the quotes. The default implementation returns no fields.
+    fn as_record_fields(&self) -> Vec<(String, String)> {
+        Vec::new()
+    }
+}
+
+/// Collection of records that are written into a single `.db` file.
+///
+/// Records of different structures can be pushed into the file, which can then be checked for
+/// duplicates with [`dedup_by_name`](Self::dedup_by_name) and written to disk.
+#[derive(Debug, Default, Clone)]
+pub struct RecordFile {
+    records: Vec<String>,
+}
+
+impl RecordFile {
+    /// Construct new empty record file.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds all records of a structure to the file.
+    pub fn push(&mut self, rec: impl AsRecord) {
+        self.records.extend(rec.as_records());
+    }
+
+    /// Adds all records of every structure in the iterator to the file.
+    pub fn extend(&mut self, iter: impl IntoIterator<Item = impl AsRecord>) {
+        iter.into_iter().for_each(|rec| self.push(rec));
+    }
+
+    /// Returns the records currently in the file.
+    pub fn records(&self) -> &[String] {
+        &self.records
+    }
+
+    /// Removes records that are exact duplicates of a previous record.
+    ///
+    /// Returns an error if two different records share the same name, since such a file would
+    /// be rejected by the IOC.
+    pub fn dedup_by_name(&mut self) -> Result<(), DuplicateRecordError> {
+        let mut names: HashMap<&str, &str> = HashMap::new();
+        let mut keep: Vec<bool> = Vec::new();
+        for record in &self.records {
+            let Some(name) = db_record_name_from_str(record) else {
+                keep.push(true);
+                continue;
+            };
+            match names.get(name) {
+                Some(&first) if first == record => keep.push(false),
+                Some(_) => return Err(DuplicateRecordError { name: name.into() }),
+                None => {
+                    names.insert(name, record);
+                    keep.push(true);
+                }
+            }
+        }
+        let mut keep = keep.into_iter();
+        self.records.retain(|_| keep.next().unwrap_or(true));
+        Ok(())
+    }
+
+    /// Writes all records to a file, overwriting it if it exists.
+    pub fn write_to_file(&self, path: impl AsRef<std::path::Path>) -> std::io::Result<()> {
+        std::fs::write(path, self.to_string())
+    }
+}
+
+impl std::fmt::Display for RecordFile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.records
+            .iter()
+            .try_for_each(|record| write!(f, "{}", record))
+    }
 }
 
+/// Writes the records of every structure in the iterator to a file, overwriting it if it exists.
+pub fn write_records<P: AsRef<std::path::Path>, I: IntoIterator<Item = impl AsRecord>>(
+    path: P,
+    records: I,
+) -> std::io::Result<()> {
+    write_records_to(std::fs::File::create(path)?, records)
+}
+
+/// Appends the records of every structure in the iterator to a file, creating it if it doesn't
+/// exist.
+pub fn append_records<P: AsRef<std::path::Path>, I: IntoIterator<Item = impl AsRecord>>(
+    path: P,
+    records: I,
+) -> std::io::Result<()> {
+    let file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)?;
+    write_records_to(file, records)
+}
+
+fn write_records_to(
+    file: std::fs::File,
+    records: impl IntoIterator<Item = impl AsRecord>,
+) -> std::io::Result<()> {
+    let mut writer = std::io::BufWriter::new(file);
+    for record in records {
+        record.as_record_write(&mut writer)?;
+    }
+    std::io::Write::flush(&mut writer)
+}
+
+/// Extracts the record name from the header line of an EPICS record block, e.g.
+/// `record(ao, "$(P)Voltage") {` -> `$(P)Voltage`. Empty and comment (`#`) lines before the
+/// header are skipped, names without quotes are accepted as well.
+///
+/// Returns `None` if the block doesn't start with a `record` (or `grecord`) header.
+///
+/// ```rust
+/// let record = "# output\nrecord(ao, \"$(P)Voltage\") {\n  field(VAL, \"0.5\")\n}\n";
+/// assert_eq!(epics_gen::db_record_name_from_str(record), Some("$(P)Voltage"));
+/// ```
+pub fn db_record_name_from_str(record: &str) -> Option<&str> {
+    let header = record
+        .lines()
+        .map(str::trim_start)
+        .find(|line| !line.is_empty() && !line.starts_with('#'))?;
+    let header = header
+        .strip_prefix("record")
+        .or_else(|| header.strip_prefix("grecord"))?
+        .trim_start()
+        .strip_prefix('(')?;
+    // skip the record type
+    let name = header[header.find(',')? + 1..].trim_start();
+    match name.strip_prefix('"') {
+        Some(name) => Some(&name[..name.find('"')?]),
+        // unquoted names can contain macros, so the header ends at the last parenthesis
+        None => Some(name[..name.rfind(')')?].trim_end()),
+    }
+}
+
+/// Error returned when a [`RecordFile`] contains different records with the same name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DuplicateRecordError {
+    name: String,
+}
+
+impl DuplicateRecordError {
+    /// Name of the duplicated record.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl std::fmt::Display for DuplicateRecordError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Duplicate record name: {}", self.name)
+    }
+}
+
+impl std::error::Error for DuplicateRecordError {}
+
+/// Checks that the string is valid EPICS database syntax, e.g. the output of
+/// [`AsRecord::as_record`] or a [`RecordFile`], before it is written to disk and loaded by the
+/// IOC.
+///
+/// `record`/`grecord` definitions with `field`, `info` and `alias` entries, top level `alias`,
+/// `include`, `path` and `addpath` statements and `#` comments are accepted. Macros (`$(P)`) are
+/// not expanded, only their syntax is checked.
+pub fn validate_db_string(db: &str) -> Result<(), DbSyntaxError> {
+    let mut parser = DbParser {
+        tokens: tokenize_db(db)?,
+        pos: 0,
+        last_line: db.lines().count().max(1),
+    };
+    while parser.peek().is_some() {
+        parser.statement()?;
+    }
+    Ok(())
+}
+
+/// Token of the EPICS database syntax.
+#[derive(Debug, Clone, PartialEq)]
+enum DbToken {
+    /// Unquoted word (record type, field name, unquoted value)
+    Word(String),
+    /// Quoted string
+    Str(String),
+    /// One of `(`, `)`, `{`, `}` or `,`
+    Punct(char),
+}
+
+impl std::fmt::Display for DbToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DbToken::Word(word) => write!(f, "`{}`", word),
+            DbToken::Str(s) => write!(f, "\"{}\"", s),
+            DbToken::Punct(c) => write!(f, "`{}`", c),
+        }
+    }
+}
+
+/// Splits the database string into tokens, together with their line numbers.
+fn tokenize_db(db: &str) -> Result<Vec<(DbToken, usize)>, DbSyntaxError> {
+    // Characters of unquoted words, the same as in the EPICS database lexer
+    let is_word_char = |c: char| c.is_ascii_alphanumeric() || "_-+:.[]<>;".contains(c);
+    let mut tokens = Vec::new();
+    let mut line = 1;
+    let mut chars = db.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\n' => line += 1,
+            c if c.is_whitespace() => {}
+            '#' => while chars.next_if(|&c| c != '\n').is_some() {},
+            '(' | ')' | '{' | '}' | ',' => tokens.push((DbToken::Punct(c), line)),
+            '"' => {
+                let mut s = String::new();
+                loop {
+                    match chars.next() {
+                        Some('"') => break,
+                        Some('\\') => match chars.next() {
+                            Some('\n') | None => {
+                                return Err(DbSyntaxError::new(line, "unterminated string"))
+                            }
+                            Some(c) => {
+                                s.push('\\');
+                                s.push(c);
+                            }
+                        },
+                        Some('\n') | None => {
+                            return Err(DbSyntaxError::new(line, "unterminated string"))
+                        }
+                        Some(c) => s.push(c),
+                    }
+                }
+                tokens.push((DbToken::Str(s), line));
+            }
+            c if is_word_char(c) || c == '$' => {
+                let mut word = String::new();
+                let mut c = Some(c);
+                while let Some(ch) = c {
+                    if ch == '$' {
+                        // Unquoted macro, `$(NAME)` or `${NAME}`
+                        let close = match chars.next() {
+                            Some('(') => ')',
+                            Some('{') => '}',
+                            _ => return Err(DbSyntaxError::new(line, "invalid macro")),
+                        };
+                        word.push('$');
+                        word.push(if close == ')' { '(' } else { '{' });
+                        loop {
+                            match chars.next() {
+                                Some(ch) if ch == close => break,
+                                Some('\n') | None => {
+                                    return Err(DbSyntaxError::new(line, "unterminated macro"))
+                                }
+                                Some(ch) => word.push(ch),
+                            }
+                        }
+                        word.push(close);
+                    } else {
+                        word.push(ch);
+                    }
+                    c = chars.next_if(|&c| is_word_char(c) || c == '$');
+                }
+                tokens.push((DbToken::Word(word), line));
+            }
+            c => {
+                return Err(DbSyntaxError::new(
+                    line,
+                    format!("unexpected character '{}'", c),
+                ))
+            }
+        }
+    }
+    Ok(tokens)
+}
+
+/// Recursive-descent parser of the EPICS database syntax.
+struct DbParser {
+    tokens: Vec<(DbToken, usize)>,
+    pos: usize,
+    /// line reported by errors at the end of the input
+    last_line: usize,
+}
+
+impl DbParser {
+    fn peek(&self) -> Option<&DbToken> {
+        self.tokens.get(self.pos).map(|(token, _)| token)
+    }
+
+    fn next(&mut self, expected: &str) -> Result<DbToken, DbSyntaxError> {
+        match self.tokens.get(self.pos) {
+            Some((token, _)) => {
+                self.pos += 1;
+                Ok(token.clone())
+            }
+            None => Err(DbSyntaxError::new(
+                self.last_line,
+                format!("expected {}, found end of input", expected),
+            )),
+        }
+    }
+
+    fn unexpected(&self, expected: &str, token: &DbToken) -> DbSyntaxError {
+        // The token was already consumed
+        let line = self.tokens[self.pos - 1].1;
+        DbSyntaxError::new(line, format!("expected {}, found {}", expected, token))
+    }
+
+    fn punct(&mut self, punct: char) -> Result<(), DbSyntaxError> {
+        let expected = format!("`{}`", punct);
+        match self.next(&expected)? {
+            DbToken::Punct(c) if c == punct => Ok(()),
+            token => Err(self.unexpected(&expected, &token)),
+        }
+    }
+
+    fn word(&mut self, expected: &str) -> Result<String, DbSyntaxError> {
+        match self.next(expected)? {
+            DbToken::Word(word) => Ok(word),
+            token => Err(self.unexpected(expected, &token)),
+        }
+    }
+
+    /// Quoted string or unquoted word.
+    fn string(&mut self, expected: &str) -> Result<String, DbSyntaxError> {
+        match self.next(expected)? {
+            DbToken::Word(s) | DbToken::Str(s) => Ok(s),
+            token => Err(self.unexpected(expected, &token)),
+        }
+    }
+
+    /// Field or info value, a string, a word or a JSON value (`{const: 1}`).
+    fn value(&mut self) -> Result<(), DbSyntaxError> {
+        if self.peek() == Some(&DbToken::Punct('{')) {
+            return self.json();
+        }
+        self.string("value").map(|_| ())
+    }
+
+    /// JSON value, only the braces need to be balanced.
+    fn json(&mut self) -> Result<(), DbSyntaxError> {
+        self.punct('{')?;
+        loop {
+            match self.next("`}`")? {
+                DbToken::Punct('}') => return Ok(()),
+                DbToken::Punct('{') => {
+                    self.pos -= 1;
+                    self.json()?;
+                }
+                DbToken::Punct('(') | DbToken::Punct(')') => {
+                    let token = self.tokens[self.pos - 1].0.clone();
+                    return Err(self.unexpected("JSON value", &token));
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn statement(&mut self) -> Result<(), DbSyntaxError> {
+        let expected = "`record`, `grecord`, `alias`, `include`, `path` or `addpath`";
+        match self.word(expected)?.as_str() {
+            "record" | "grecord" => self.record(),
+            "alias" => {
+                self.punct('(')?;
+                self.string("record name")?;
+                self.punct(',')?;
+                self.string("alias name")?;
+                self.punct(')')
+            }
+            "include" | "path" | "addpath" => match self.next("string")? {
+                DbToken::Str(_) => Ok(()),
+                token => Err(self.unexpected("string", &token)),
+            },
+            word => Err(self.unexpected(expected, &DbToken::Word(word.to_string()))),
+        }
+    }
+
+    fn record(&mut self) -> Result<(), DbSyntaxError> {
+        self.punct('(')?;
+        self.word("record type")?;
+        self.punct(',')?;
+        if self.string("record name")?.is_empty() {
+            return Err(DbSyntaxError::new(
+                self.tokens[self.pos - 1].1,
+                "record name is empty",
+            ));
+        }
+        self.punct(')')?;
+        // Record body is optional
+        if self.peek() != Some(&DbToken::Punct('{')) {
+            return Ok(());
+        }
+        self.punct('{')?;
+        loop {
+            let expected = "`field`, `info`, `alias` or `}`";
+            match self.next(expected)? {
+                DbToken::Punct('}') => return Ok(()),
+                DbToken::Word(word) if word == "field" => {
+                    self.punct('(')?;
+                    self.word("field name")?;
+                    self.punct(',')?;
+                    self.value()?;
+                    self.punct(')')?;
+                }
+                DbToken::Word(word) if word == "info" => {
+                    self.punct('(')?;
+                    self.string("info name")?;
+                    self.punct(',')?;
+                    self.value()?;
+                    self.punct(')')?;
+                }
+                DbToken::Word(word) if word == "alias" => {
+                    self.punct('(')?;
+                    self.string("alias name")?;
+                    self.punct(')')?;
+                }
+                token => return Err(self.unexpected(expected, &token)),
+            }
+        }
+    }
+}
+
+/// Error returned by [`validate_db_string`] when the string is not valid EPICS database syntax.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DbSyntaxError {
+    line: usize,
+    description: String,
+}
+
+impl DbSyntaxError {
+    fn new(line: usize, description: impl Into<String>) -> Self {
+        Self {
+            line,
+            description: description.into(),
+        }
+    }
+
+    /// Line of the error, starting at 1.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// Description of the error.
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+}
+
+impl std::fmt::Display for DbSyntaxError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Invalid database syntax, line {}: {}",
+            self.line, self.description
+        )
+    }
+}
+
+impl std::error::Error for DbSyntaxError {}
+
 /// Interface that is used to convert XlsxData to target type.
 ///
 /// This trait is used when traversing the XlsxRow and converting each cell to associated struct
@@ -549,3 +2019,62 @@ impl FromXlsxData for String {
             .ok_or(Self::Error::ValueMissing)
     }
 }
+
+/// Accepts boolean cells and `true`/`false`, `yes`/`no`, `1`/`0` strings (case-insensitive).
+impl FromXlsxData for bool {
+    type Error = ParseErrorKind;
+
+    fn from_xlsx_data(data: XlsxData) -> Result<Self, Self::Error> {
+        match data {
+            XlsxData::Bool(val) => Ok(val),
+            XlsxData::String(val) => match val.trim().to_uppercase().as_str() {
+                "YES" | "TRUE" | "1" => Ok(true),
+                "NO" | "FALSE" | "0" => Ok(false),
+                _ => Err(Self::Error::InvalidValue),
+            },
+            XlsxData::Empty => Err(Self::Error::ValueMissing),
+            _ => Err(Self::Error::InvalidValue),
+        }
+    }
+}
+
+/// Converts a numeric integer cell (calamine stores most integers as floats) to `i64`. Floats with
+/// a fractional part or outside of the `i64` range are invalid.
+fn get_integer(data: XlsxData) -> Result<i64, ParseErrorKind> {
+    match data {
+        XlsxData::Int(val) => Ok(val),
+        XlsxData::Float(val) => {
+            // `i64::MAX as f64` is rounded up to 2^63, which is already out of range
+            if val.fract() != 0.0 || !(i64::MIN as f64..i64::MAX as f64).contains(&val) {
+                return Err(ParseErrorKind::InvalidValue);
+            }
+            Ok(val as i64)
+        }
+        XlsxData::Empty => Err(ParseErrorKind::ValueMissing),
+        _ => Err(ParseErrorKind::InvalidValue),
+    }
+}
+
+macro_rules! impl_from_xlsx_data_for_int {
+    ($($ty:ty),*) => {
+        $(
+            impl FromXlsxData for $ty {
+                type Error = ParseErrorKind;
+
+                fn from_xlsx_data(data: XlsxData) -> Result<Self, Self::Error> {
+                    match data {
+                        // Parsed directly, so e.g. `u64` values above `i64::MAX` can be read
+                        XlsxData::String(val) => {
+                            val.trim().parse().map_err(|_| Self::Error::InvalidValue)
+                        }
+                        data => get_integer(data)?
+                            .try_into()
+                            .map_err(|_| Self::Error::InvalidValue),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_from_xlsx_data_for_int!(i32, i64, u32, u64, usize);
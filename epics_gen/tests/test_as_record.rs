@@ -1,4 +1,4 @@
-use epics_gen_macros::AsRecord;
+use epics_gen::AsRecord;
 
 #[test]
 fn test_as_record_single() {
@@ -89,6 +89,31 @@ record(ao, "$(P)SomeOut") { field(VAL, "0.5") }
     );
 }
 
+#[test]
+fn test_as_record_fmt_file() {
+    #[derive(AsRecord)]
+    struct TestStruct {
+        #[record(fmt_file = "tests/test_template.db")]
+        nelm: u32,
+        #[record(fmt = r#"record(ao, "$(P)SomeOut") {{ field(VAL, "{}") }}"#)]
+        val: f64,
+    }
+
+    assert_eq!(
+        TestStruct {
+            nelm: 1024,
+            val: 0.5
+        }
+        .as_record(),
+        r#"record(waveform, "$(P)Samples") {
+  field(FTVL, "DOUBLE")
+  field(NELM, "1024")
+}
+record(ao, "$(P)SomeOut") { field(VAL, "0.5") }
+"#
+    );
+}
+
 #[test]
 fn test_as_record_named() {
     #[derive(Copy, Clone, Debug, Default, PartialEq)]
@@ -168,3 +193,1416 @@ fn test_as_record_repr() {
 "#
     );
 }
+
+#[test]
+fn test_as_record_repr_path() {
+    #[derive(AsRecord)]
+    struct TestStruct {
+        #[record(rec_name = "TestRec", rec_type = "longout", field = "VAL", repr = std::primitive::u32)]
+        val: f64,
+    }
+
+    let test_struct = TestStruct { val: 7.9 };
+
+    assert_eq!(
+        test_struct.as_record(),
+        r#"record(longout, "TestRec") {
+  field(VAL, "7")
+}
+"#
+    );
+}
+
+#[test]
+fn test_as_record_skip() {
+    #[derive(AsRecord)]
+    #[record(rec_name = "$(P)Voltage", rec_type = "ao")]
+    struct TestStruct {
+        #[record(field = "VAL")]
+        val: f64,
+        #[record(skip)]
+        #[allow(dead_code)]
+        row_num: usize,
+    }
+
+    let test_struct = TestStruct {
+        val: 0.5,
+        row_num: 3,
+    };
+
+    assert_eq!(
+        test_struct.as_record(),
+        r#"record(ao, "$(P)Voltage") {
+  field(VAL, "0.5")
+}
+"#
+    );
+}
+
+#[test]
+fn test_as_record_precision() {
+    #[derive(AsRecord)]
+    #[record(rec_name = "$(P)Voltage", rec_type = "ao")]
+    struct TestStruct {
+        #[record(field = "VAL", precision = 2)]
+        val: f64,
+        #[record(field = "HOPR", precision = 0)]
+        hopr: f64,
+    }
+
+    let test_struct = TestStruct {
+        val: std::f64::consts::PI,
+        hopr: 9.6,
+    };
+
+    assert_eq!(
+        test_struct.as_record(),
+        r#"record(ao, "$(P)Voltage") {
+  field(VAL, "3.14")
+  field(HOPR, "10")
+}
+"#
+    );
+}
+
+#[test]
+fn test_as_record_hex_bin() {
+    #[derive(Clone, Copy)]
+    enum Mode {
+        #[allow(dead_code)]
+        Off,
+        Burst = 26,
+    }
+
+    #[derive(AsRecord)]
+    #[record(rec_name = "$(P)Reg", rec_type = "longout")]
+    struct TestStruct {
+        #[record(field = "VAL", hex)]
+        val: u32,
+        #[record(field = "DESC", bin)]
+        mask: u8,
+        #[record(field = "DOL", repr = u32, hex)]
+        mode: Mode,
+    }
+
+    let test_struct = TestStruct {
+        val: 26,
+        mask: 26,
+        mode: Mode::Burst,
+    };
+
+    assert_eq!(
+        test_struct.as_record(),
+        r#"record(longout, "$(P)Reg") {
+  field(VAL, "0x1a")
+  field(DESC, "0b11010")
+  field(DOL, "0x1a")
+}
+"#
+    );
+}
+
+#[test]
+fn test_as_record_multiple_subst() {
+    #[derive(AsRecord)]
+    struct TestStruct {
+        #[record(subst = "$(MxcId)")]
+        mxc: u8,
+        #[record(subst = "$(ChannelId)")]
+        channel: u8,
+        #[record(fmt = r#"record(ao, "$(P)Mxc$(MxcId)Ch$(ChannelId)Out") {{ field(VAL, "{}") }}"#)]
+        val: f64,
+    }
+
+    let test_struct = TestStruct {
+        mxc: 1,
+        channel: 7,
+        val: 0.5,
+    };
+
+    assert_eq!(
+        test_struct.as_record(),
+        r#"record(ao, "$(P)Mxc1Ch7Out") { field(VAL, "0.5") }
+"#
+    );
+}
+
+#[test]
+fn test_as_record_subst_field() {
+    #[derive(AsRecord)]
+    #[record(
+        rec_name = "$(P)CH$(CH_IDX):Voltage",
+        rec_type = "ao",
+        subst_field = "ch_idx",
+        subst_pattern = "$(CH_IDX)"
+    )]
+    struct Channel {
+        #[record(field = "VAL")]
+        val: f64,
+        #[record(field = "PHAS")]
+        ch_idx: usize,
+    }
+
+    assert_eq!(
+        Channel {
+            val: 0.5,
+            ch_idx: 3
+        }
+        .as_record(),
+        r#"record(ao, "$(P)CH3:Voltage") {
+  field(VAL, "0.5")
+  field(PHAS, "3")
+}
+"#
+    );
+}
+
+#[test]
+fn test_as_record_repr_expr() {
+    #[derive(AsRecord)]
+    #[record(rec_name = "$(P)Voltage", rec_type = "ao", egu = "mV")]
+    struct SingleRecord {
+        #[record(field = "VAL", repr_expr = "self.val * 1000.0")]
+        val: f64,
+    }
+
+    #[derive(AsRecord)]
+    struct MultiRecord {
+        #[record(
+            rec_name = "$(P)Current",
+            rec_type = "ao",
+            field = "VAL",
+            repr_expr = "self.current.abs()"
+        )]
+        current: f64,
+    }
+
+    assert_eq!(
+        SingleRecord { val: 1.5 }.as_record(),
+        r#"record(ao, "$(P)Voltage") {
+  field(EGU, "mV")
+  field(VAL, "1500")
+}
+"#
+    );
+    assert_eq!(
+        MultiRecord { current: -0.25 }.as_record(),
+        r#"record(ao, "$(P)Current") {
+  field(VAL, "0.25")
+}
+"#
+    );
+}
+
+#[test]
+fn test_as_record_as_record_fn() {
+    #[derive(AsRecord)]
+    #[record(
+        rec_name = "$(P)Voltage",
+        rec_type = "ao",
+        as_record_fn = "to_db_record"
+    )]
+    struct SingleRecord {
+        #[record(field = "VAL")]
+        val: f64,
+    }
+
+    impl SingleRecord {
+        // Inherent method with the same name as the trait method
+        fn as_record(&self) -> String {
+            format!("voltage: {}", self.val)
+        }
+    }
+
+    let record = SingleRecord { val: 0.5 };
+    assert_eq!(record.as_record(), "voltage: 0.5");
+    assert_eq!(
+        record.to_db_record(),
+        r#"record(ao, "$(P)Voltage") {
+  field(VAL, "0.5")
+}
+"#
+    );
+    assert_eq!(record.to_db_record(), AsRecord::as_record(&record));
+}
+
+#[test]
+fn test_as_record_impl_display() {
+    #[derive(AsRecord)]
+    #[record(rec_name = "$(P)Voltage", rec_type = "ao", impl_display)]
+    struct SingleRecord {
+        #[record(field = "VAL")]
+        val: f64,
+    }
+
+    #[derive(AsRecord)]
+    #[record(impl_display)]
+    struct MultipleRecords<T> {
+        #[record(rec_name = "$(P)Current", rec_type = "ai", field = "VAL")]
+        val: T,
+    }
+
+    let record = SingleRecord { val: 0.5 };
+    assert_eq!(format!("{}", record), record.as_record());
+    let record = MultipleRecords { val: 1.5 };
+    assert_eq!(
+        record.to_string(),
+        r#"record(ai, "$(P)Current") {
+  field(VAL, "1.5")
+}
+"#
+    );
+}
+
+#[test]
+fn test_as_record_fields() {
+    #[derive(AsRecord)]
+    #[record(rec_name = "$(P)CH$(CH):Voltage", rec_type = "ao", egu = "V")]
+    struct SingleRecord {
+        #[record(subst = "$(CH)")]
+        channel: u8,
+        #[record(field = "VAL", precision = 2)]
+        val: f64,
+        #[record(field = "OUT")]
+        out: &'static str,
+        #[record(field = "DRVH", field_if = "self.drvh > 0.0")]
+        drvh: f64,
+    }
+
+    #[derive(AsRecord)]
+    #[record(desc = "Channel")]
+    struct MultiRecord {
+        #[record(rec_name = "$(P)Current", rec_type = "ao", field = "VAL")]
+        current: f64,
+        #[record(fmt = r#"record(bo, "$(P)Enable") {{ field(VAL, "{}") }}"#)]
+        enable: u8,
+    }
+
+    let record = SingleRecord {
+        channel: 3,
+        val: 0.5,
+        out: "@ch$(CH)",
+        drvh: 0.0,
+    };
+    assert_eq!(
+        record.as_record_fields(),
+        vec![
+            ("EGU".to_string(), "V".to_string()),
+            ("VAL".to_string(), "0.50".to_string()),
+            ("OUT".to_string(), "@ch3".to_string()),
+        ]
+    );
+
+    let record = MultiRecord {
+        current: 0.25,
+        enable: 1,
+    };
+    assert_eq!(
+        record.as_record_fields(),
+        vec![
+            ("DESC".to_string(), "Channel".to_string()),
+            ("VAL".to_string(), "0.25".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn test_as_record_link_field() {
+    #[derive(AsRecord)]
+    #[record(rec_name = "$(P)Voltage", rec_type = "ai")]
+    struct SingleRecord {
+        #[record(
+            link_field = "INP",
+            link_target = "$(P)Source.VAL",
+            link_modifier = "PP"
+        )]
+        inp: &'static str,
+        #[record(link_field = "FLNK", link_target = "$(P){}")]
+        flnk: &'static str,
+        #[record(link_field = "SDIS", link_modifier = "NPP NMS")]
+        sdis: &'static str,
+    }
+
+    let record = SingleRecord {
+        inp: "",
+        flnk: "Current",
+        sdis: "${P}Disable",
+    };
+    assert_eq!(
+        record.as_record(),
+        r#"record(ai, "$(P)Voltage") {
+  field(INP, "$(P)Source.VAL PP")
+  field(FLNK, "$(P)Current")
+  field(SDIS, "${P}Disable NPP NMS")
+}
+"#
+    );
+    assert_eq!(
+        record.as_record_fields()[0],
+        ("INP".to_string(), "$(P)Source.VAL PP".to_string())
+    );
+
+    #[derive(AsRecord)]
+    struct MultipleRecords {
+        #[record(
+            rec_name = "$(P)Current",
+            rec_type = "ao",
+            link_field = "OUT",
+            link_target = "$(P)Dac{}.VAL",
+            link_modifier = "PP"
+        )]
+        dac: u8,
+    }
+
+    assert_eq!(
+        MultipleRecords { dac: 2 }.as_record(),
+        r#"record(ao, "$(P)Current") {
+  field(OUT, "$(P)Dac2.VAL PP")
+}
+"#
+    );
+}
+
+#[test]
+fn test_as_record_option_default() {
+    #[derive(AsRecord)]
+    #[record(rec_name = "$(P)Voltage", rec_type = "ao")]
+    struct SingleRecord {
+        #[record(field = "VAL", default = "0", precision = 2)]
+        val: Option<f64>,
+        #[record(field = "DRVH", default = "10", repr = u8)]
+        drvh: Option<f64>,
+        #[record(field = "EGU", default = "V")]
+        egu: Option<String>,
+    }
+
+    let record = SingleRecord {
+        val: None,
+        drvh: Some(5.5),
+        egu: None,
+    };
+    assert_eq!(
+        record.as_record(),
+        r#"record(ao, "$(P)Voltage") {
+  field(VAL, "0")
+  field(DRVH, "5")
+  field(EGU, "V")
+}
+"#
+    );
+
+    #[derive(AsRecord)]
+    struct MultipleRecords {
+        #[record(
+            rec_name = "$(P)Current",
+            rec_type = "ao",
+            field = "VAL",
+            default = "0",
+            precision = 1
+        )]
+        current: Option<f64>,
+    }
+
+    assert_eq!(
+        MultipleRecords {
+            current: Some(0.25)
+        }
+        .as_record(),
+        r#"record(ao, "$(P)Current") {
+  field(VAL, "0.2")
+}
+"#
+    );
+    assert_eq!(
+        MultipleRecords { current: None }.as_record_fields(),
+        vec![("VAL".to_string(), "0".to_string())]
+    );
+}
+
+#[test]
+fn test_as_record_pini() {
+    #[derive(AsRecord)]
+    #[record(rec_name = "$(P)Voltage", rec_type = "ao", pini)]
+    struct SingleRecord {
+        #[record(field = "VAL")]
+        val: f64,
+    }
+
+    assert_eq!(
+        SingleRecord { val: 0.5 }.as_record(),
+        r#"record(ao, "$(P)Voltage") {
+  field(PINI, "YES")
+  field(VAL, "0.5")
+}
+"#
+    );
+
+    #[derive(AsRecord)]
+    #[record(pini)]
+    struct MultipleRecords {
+        #[record(rec_name = "$(P)Voltage", rec_type = "ao", field = "VAL")]
+        voltage: f64,
+        #[record(rec_name = "$(P)Current", rec_type = "ao", field = "VAL")]
+        current: f64,
+    }
+
+    assert_eq!(
+        MultipleRecords {
+            voltage: 5.5,
+            current: 0.5
+        }
+        .as_record(),
+        r#"record(ao, "$(P)Voltage") {
+  field(PINI, "YES")
+  field(VAL, "5.5")
+}
+record(ao, "$(P)Current") {
+  field(PINI, "YES")
+  field(VAL, "0.5")
+}
+"#
+    );
+}
+
+#[test]
+fn test_as_record_scan() {
+    #[derive(AsRecord)]
+    #[record(rec_name = "$(P)Voltage", rec_type = "ai", scan = "1 second", pini)]
+    struct TestStruct {
+        #[record(field = "VAL")]
+        val: f64,
+    }
+
+    assert_eq!(
+        TestStruct { val: 0.5 }.as_record(),
+        r#"record(ai, "$(P)Voltage") {
+  field(SCAN, "1 second")
+  field(PINI, "YES")
+  field(VAL, "0.5")
+}
+"#
+    );
+}
+
+#[test]
+fn test_as_records() {
+    #[derive(AsRecord)]
+    struct TestStruct {
+        #[record(subst = "$(Ch)")]
+        ch: u8,
+        #[record(rec_name = "$(P)Ch$(Ch)Voltage", rec_type = "ao", field = "VAL")]
+        voltage: f64,
+        #[record(fmt = r#"record(ao, "$(P)Ch$(Ch)Current") {{ field(VAL, "{}") }}"#)]
+        current: f64,
+    }
+
+    let test_struct = TestStruct {
+        ch: 2,
+        voltage: 5.5,
+        current: 0.5,
+    };
+
+    assert_eq!(
+        test_struct.as_records(),
+        vec![
+            r#"record(ao, "$(P)Ch2Voltage") {
+  field(VAL, "5.5")
+}
+"#,
+            r#"record(ao, "$(P)Ch2Current") { field(VAL, "0.5") }
+"#
+        ]
+    );
+    assert_eq!(test_struct.as_records().concat(), test_struct.as_record());
+}
+
+#[test]
+fn test_as_record_dyn() {
+    #[derive(AsRecord)]
+    #[record(rec_name = "$(P)Voltage", rec_type = "ao")]
+    struct Voltage {
+        #[record(field = "VAL")]
+        val: f64,
+    }
+
+    #[derive(AsRecord)]
+    #[record(rec_name = "$(P)Enable", rec_type = "bo")]
+    struct Enable {
+        #[record(field = "VAL", repr = u8)]
+        val: bool,
+    }
+
+    let records: Vec<Box<dyn AsRecord>> = vec![
+        Box::new(Voltage { val: 0.5 }),
+        Box::new(Enable { val: true }),
+    ];
+
+    assert_eq!(
+        records
+            .iter()
+            .flat_map(|record| record.as_records())
+            .collect::<Vec<String>>(),
+        vec![
+            r#"record(ao, "$(P)Voltage") {
+  field(VAL, "0.5")
+}
+"#,
+            r#"record(bo, "$(P)Enable") {
+  field(VAL, "1")
+}
+"#
+        ]
+    );
+}
+
+#[test]
+fn test_as_record_write() {
+    #[derive(AsRecord)]
+    #[record(rec_name = "$(P)Voltage", rec_type = "ao")]
+    struct Single {
+        #[record(field = "VAL")]
+        val: f64,
+    }
+
+    #[derive(AsRecord)]
+    struct Multiple {
+        #[record(subst = "$(Ch)")]
+        ch: u8,
+        #[record(rec_name = "$(P)Ch$(Ch)Voltage", rec_type = "ao", field = "VAL")]
+        voltage: f64,
+    }
+
+    let single = Single { val: 0.5 };
+    let mut buf: Vec<u8> = Vec::new();
+    single.as_record_write(&mut buf).unwrap();
+    assert_eq!(String::from_utf8(buf).unwrap(), single.as_record());
+
+    let multiple = Multiple {
+        ch: 1,
+        voltage: 5.5,
+    };
+    let mut buf: Vec<u8> = Vec::new();
+    multiple.as_record_write(&mut buf).unwrap();
+    assert_eq!(String::from_utf8(buf).unwrap(), multiple.as_record());
+}
+
+#[test]
+fn test_as_record_dtyp() {
+    #[derive(AsRecord)]
+    #[record(
+        rec_name = "$(P)Voltage",
+        rec_type = "ai",
+        dtyp = "Raw Soft Channel",
+        scan = "I/O Intr"
+    )]
+    struct TestStruct {
+        #[record(field = "VAL")]
+        val: f64,
+    }
+
+    assert_eq!(
+        TestStruct { val: 0.5 }.as_record(),
+        r#"record(ai, "$(P)Voltage") {
+  field(DTYP, "Raw Soft Channel")
+  field(SCAN, "I/O Intr")
+  field(VAL, "0.5")
+}
+"#
+    );
+}
+
+#[test]
+fn test_as_record_desc() {
+    #[derive(AsRecord)]
+    #[record(desc = "Output Voltage", pini)]
+    struct TestStruct {
+        #[record(rec_name = "$(P)Voltage", rec_type = "ao", field = "VAL")]
+        val: f64,
+    }
+
+    assert_eq!(
+        TestStruct { val: 0.5 }.as_record(),
+        r#"record(ao, "$(P)Voltage") {
+  field(DESC, "Output Voltage")
+  field(PINI, "YES")
+  field(VAL, "0.5")
+}
+"#
+    );
+}
+
+#[test]
+fn test_as_record_rec_name_len() {
+    // 60 characters is the longest allowed record name, names with macros are not checked
+    #[derive(AsRecord)]
+    #[record(
+        rec_name = "PS1:AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA",
+        rec_type = "ao"
+    )]
+    struct MaxLen {
+        #[record(field = "VAL")]
+        val: f64,
+    }
+
+    #[derive(AsRecord)]
+    #[record(
+        rec_name = "$(P)PS1:AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA",
+        rec_type = "ao"
+    )]
+    struct WithMacro {
+        #[record(field = "VAL")]
+        val: f64,
+    }
+
+    assert!(MaxLen { val: 0.5 }
+        .as_record()
+        .contains("PS1:AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA"));
+    assert!(WithMacro { val: 0.5 }
+        .as_record()
+        .contains("$(P)PS1:AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA"));
+}
+
+#[test]
+fn test_as_record_field_if() {
+    #[derive(AsRecord)]
+    #[record(rec_name = "$(P)Voltage", rec_type = "ao")]
+    struct SingleRecord {
+        #[record(field = "VAL")]
+        val: f64,
+        #[record(field = "EGU", field_if = "!self.egu.is_empty()")]
+        egu: String,
+    }
+
+    assert_eq!(
+        SingleRecord {
+            val: 0.5,
+            egu: "V".to_string()
+        }
+        .as_record(),
+        r#"record(ao, "$(P)Voltage") {
+  field(VAL, "0.5")
+  field(EGU, "V")
+}
+"#
+    );
+    assert_eq!(
+        SingleRecord {
+            val: 0.5,
+            egu: String::new()
+        }
+        .as_record(),
+        r#"record(ao, "$(P)Voltage") {
+  field(VAL, "0.5")
+}
+"#
+    );
+
+    #[derive(AsRecord)]
+    struct MultipleRecords {
+        #[record(rec_name = "$(P)Voltage", rec_type = "ao", field = "VAL")]
+        voltage: f64,
+        #[record(
+            rec_name = "$(P)Current",
+            rec_type = "ao",
+            field = "VAL",
+            field_if = "self.current > 0.0"
+        )]
+        current: f64,
+    }
+
+    let records = MultipleRecords {
+        voltage: 5.0,
+        current: 0.0,
+    };
+    assert_eq!(records.as_records().len(), 1);
+    assert_eq!(
+        records.as_record(),
+        r#"record(ao, "$(P)Voltage") {
+  field(VAL, "5")
+}
+"#
+    );
+}
+
+#[test]
+fn test_as_record_global_prefix() {
+    #[derive(AsRecord)]
+    #[record(rec_name = "Voltage", rec_type = "ao", global_prefix = "$(P)")]
+    struct SingleRecord {
+        #[record(field = "VAL")]
+        val: f64,
+    }
+
+    assert_eq!(
+        SingleRecord { val: 0.5 }.as_record(),
+        r#"record(ao, "$(P)Voltage") {
+  field(VAL, "0.5")
+}
+"#
+    );
+
+    #[derive(AsRecord)]
+    #[record(global_prefix = "$(P)")]
+    struct MultipleRecords {
+        #[record(rec_name = "Voltage", rec_type = "ao", field = "VAL")]
+        voltage: f64,
+        #[record(rec_name = "Current", rec_type = "ao", field = "VAL")]
+        current: f64,
+    }
+
+    assert_eq!(
+        MultipleRecords {
+            voltage: 5.0,
+            current: 0.5
+        }
+        .as_record(),
+        r#"record(ao, "$(P)Voltage") {
+  field(VAL, "5")
+}
+record(ao, "$(P)Current") {
+  field(VAL, "0.5")
+}
+"#
+    );
+}
+
+#[test]
+fn test_as_record_global_suffix() {
+    #[derive(AsRecord)]
+    #[record(global_prefix = "$(P)", global_suffix = "-RB")]
+    struct MultipleRecords {
+        #[record(rec_name = "Voltage", rec_type = "ai", field = "VAL")]
+        voltage: f64,
+    }
+
+    assert_eq!(
+        MultipleRecords { voltage: 5.0 }.as_record(),
+        r#"record(ai, "$(P)Voltage-RB") {
+  field(VAL, "5")
+}
+"#
+    );
+}
+
+#[test]
+fn test_as_record_rec_name_expr() {
+    #[derive(AsRecord)]
+    #[record(
+        rec_name_expr = r#"format!("CH{}Val", self.channel)"#,
+        rec_type = "ao",
+        global_prefix = "$(P)"
+    )]
+    struct SingleRecord {
+        channel: u8,
+        #[record(field = "VAL")]
+        val: f64,
+    }
+
+    assert_eq!(
+        SingleRecord {
+            channel: 2,
+            val: 0.5
+        }
+        .as_record(),
+        r#"record(ao, "$(P)CH2Val") {
+  field(VAL, "0.5")
+}
+"#
+    );
+
+    #[derive(AsRecord)]
+    #[record(rec_name_expr = "self.channel_name", rec_type = "ai")]
+    struct NamedRecord {
+        channel_name: String,
+        #[record(field = "EGU")]
+        egu: &'static str,
+    }
+
+    assert_eq!(
+        NamedRecord {
+            channel_name: "$(P)Temp".to_string(),
+            egu: "C"
+        }
+        .as_record(),
+        r#"record(ai, "$(P)Temp") {
+  field(EGU, "C")
+}
+"#
+    );
+}
+
+#[test]
+fn test_as_record_egu() {
+    #[derive(AsRecord)]
+    #[record(
+        rec_name = "$(P)Voltage",
+        rec_type = "ao",
+        dtyp = "Soft Channel",
+        egu = "V"
+    )]
+    #[record(desc = "Output voltage")]
+    struct SingleRecord {
+        #[record(field = "VAL")]
+        val: f64,
+    }
+
+    assert_eq!(
+        SingleRecord { val: 0.5 }.as_record(),
+        r#"record(ao, "$(P)Voltage") {
+  field(DESC, "Output voltage")
+  field(EGU, "V")
+  field(DTYP, "Soft Channel")
+  field(VAL, "0.5")
+}
+"#
+    );
+}
+
+#[test]
+fn test_as_record_calc_expr() {
+    #[derive(AsRecord)]
+    #[record(rec_name = "$(P)Sum", rec_type = "calc", calc_expr = "A+B*ABS(C)")]
+    struct SingleRecord {
+        #[record(field = "INPA")]
+        inpa: &'static str,
+        #[record(field = "INPB")]
+        inpb: &'static str,
+    }
+
+    assert_eq!(
+        SingleRecord {
+            inpa: "$(P)A",
+            inpb: "$(P)B"
+        }
+        .as_record(),
+        r#"record(calc, "$(P)Sum") {
+  field(CALC, "A+B*ABS(C)")
+  field(INPA, "$(P)A")
+  field(INPB, "$(P)B")
+}
+"#
+    );
+}
+
+#[test]
+fn test_as_record_alarm_limits() {
+    #[derive(AsRecord)]
+    #[record(
+        rec_name = "$(P)Temp",
+        rec_type = "ai",
+        lolo = -10,
+        low = 0.5,
+        high = "$(HIGH)",
+        hihi = 100.0
+    )]
+    struct SingleRecord {
+        #[record(field = "INP")]
+        inp: &'static str,
+    }
+
+    assert_eq!(
+        SingleRecord { inp: "@dev" }.as_record(),
+        r#"record(ai, "$(P)Temp") {
+  field(LOLO, "-10")
+  field(LOW, "0.5")
+  field(HIGH, "$(HIGH)")
+  field(HIHI, "100.0")
+  field(INP, "@dev")
+}
+"#
+    );
+}
+
+#[test]
+fn test_as_record_mbbi_states() {
+    #[derive(AsRecord)]
+    #[record(
+        rec_name = "$(P)State",
+        rec_type = "mbbi",
+        mbbi_states = ["Off", "On", "Fault"],
+        mbbi_values = [0, 1, 2]
+    )]
+    struct SingleRecord {
+        #[record(field = "INP")]
+        inp: &'static str,
+    }
+
+    assert_eq!(
+        SingleRecord { inp: "@dev" }.as_record(),
+        r#"record(mbbi, "$(P)State") {
+  field(ZRST, "Off")
+  field(ZRVL, "0")
+  field(ONST, "On")
+  field(ONVL, "1")
+  field(TWST, "Fault")
+  field(TWVL, "2")
+  field(INP, "@dev")
+}
+"#
+    );
+}
+
+#[test]
+fn test_as_record_newtype() {
+    #[derive(AsRecord)]
+    #[record(rec_name = "$(P)Voltage", rec_type = "ao")]
+    struct VoltageData {
+        #[record(field = "VAL")]
+        val: f64,
+    }
+
+    #[derive(AsRecord)]
+    struct VoltageRecord(VoltageData);
+
+    let rec = VoltageRecord(VoltageData { val: 0.5 });
+    assert_eq!(rec.as_record(), rec.0.as_record());
+    assert_eq!(rec.as_records(), vec![rec.0.as_record()]);
+}
+
+#[test]
+fn test_as_record_rec_syntax() {
+    #[derive(AsRecord)]
+    #[record(rec_name = "$(P)Voltage", rec_type = "ao", rec_syntax = "grecord")]
+    struct SingleRecord {
+        #[record(field = "VAL")]
+        val: f64,
+    }
+
+    #[derive(AsRecord)]
+    #[record(rec_syntax = "grecord")]
+    struct MultipleRecords {
+        #[record(rec_name = "$(P)Current", rec_type = "ai", field = "VAL")]
+        val: f64,
+    }
+
+    assert_eq!(
+        SingleRecord { val: 0.5 }.as_record(),
+        r#"grecord(ao, "$(P)Voltage") {
+  field(VAL, "0.5")
+}
+"#
+    );
+    assert_eq!(
+        MultipleRecords { val: 1.5 }.as_record(),
+        r#"grecord(ai, "$(P)Current") {
+  field(VAL, "1.5")
+}
+"#
+    );
+}
+
+#[test]
+fn test_as_record_indent() {
+    #[derive(AsRecord)]
+    #[record(rec_name = "$(P)Voltage", rec_type = "ao", egu = "V", indent = 4)]
+    struct SingleRecord {
+        #[record(field = "VAL", comment = "setpoint")]
+        val: f64,
+    }
+
+    #[derive(AsRecord)]
+    #[record(indent = "\t")]
+    struct MultipleRecords {
+        #[record(rec_name = "$(P)Current", rec_type = "ai", field = "VAL")]
+        val: f64,
+    }
+
+    assert_eq!(
+        SingleRecord { val: 0.5 }.as_record(),
+        r#"record(ao, "$(P)Voltage") {
+    field(EGU, "V")
+    # setpoint
+    field(VAL, "0.5")
+}
+"#
+    );
+    assert_eq!(
+        MultipleRecords { val: 1.5 }.as_record(),
+        "record(ai, \"$(P)Current\") {\n\tfield(VAL, \"1.5\")\n}\n"
+    );
+}
+
+#[test]
+fn test_as_record_pad_fields() {
+    #[derive(AsRecord)]
+    #[record(
+        rec_name = "$(P)Voltage",
+        rec_type = "ao",
+        desc = "Output Voltage",
+        egu = "V",
+        pad_fields = true
+    )]
+    struct SingleRecord {
+        #[record(field = "VAL")]
+        val: f64,
+        #[record(field = "DRVH")]
+        drvh: f64,
+    }
+
+    #[derive(AsRecord)]
+    #[record(pad_fields = true)]
+    struct MultipleRecords {
+        #[record(rec_name = "$(P)Current", rec_type = "ai", field = "VAL")]
+        val: f64,
+        #[record(rec_name = "$(P)Status", rec_type = "bi", field = "ZNAM")]
+        status: &'static str,
+    }
+
+    assert_eq!(
+        SingleRecord {
+            val: 0.5,
+            drvh: 10.0
+        }
+        .as_record(),
+        r#"record(ao, "$(P)Voltage") {
+  field(DESC, "Output Voltage")
+  field(EGU,  "V")
+  field(VAL,  "0.5")
+  field(DRVH, "10")
+}
+"#
+    );
+    assert_eq!(
+        MultipleRecords {
+            val: 1.5,
+            status: "Off"
+        }
+        .as_record(),
+        r#"record(ai, "$(P)Current") {
+  field(VAL,  "1.5")
+}
+record(bi, "$(P)Status") {
+  field(ZNAM, "Off")
+}
+"#
+    );
+}
+
+#[test]
+fn test_as_record_lifetimes() {
+    #[derive(AsRecord)]
+    #[record(rec_name = "$(P)Voltage", rec_type = "ao")]
+    struct SingleRecord<'a> {
+        #[record(field = "DESC")]
+        desc: &'a str,
+    }
+
+    #[derive(AsRecord)]
+    struct MultipleRecords<'a, T> {
+        #[record(rec_name = "$(P)Current", rec_type = "ai", field = "VAL")]
+        val: &'a T,
+    }
+
+    #[derive(AsRecord)]
+    struct Wrapper<'a>(SingleRecord<'a>);
+
+    let desc = String::from("Output voltage");
+    assert_eq!(
+        Wrapper(SingleRecord { desc: &desc }).as_record(),
+        r#"record(ao, "$(P)Voltage") {
+  field(DESC, "Output voltage")
+}
+"#
+    );
+    assert_eq!(
+        MultipleRecords { val: &1.5 }.as_record(),
+        r#"record(ai, "$(P)Current") {
+  field(VAL, "1.5")
+}
+"#
+    );
+}
+
+#[test]
+fn test_as_record_generics() {
+    #[derive(AsRecord)]
+    #[record(rec_name = "$(P)Voltage", rec_type = "ao")]
+    struct SingleRecord<T> {
+        #[record(field = "VAL")]
+        val: T,
+    }
+
+    #[derive(AsRecord)]
+    #[record(bound = "T: std::fmt::Debug")]
+    struct MultipleRecords<T> {
+        #[record(
+            rec_name = "$(P)Mode",
+            rec_type = "stringout",
+            field = "VAL",
+            repr = "debug"
+        )]
+        mode: T,
+    }
+
+    #[derive(Debug)]
+    enum Mode {
+        Fast,
+    }
+
+    #[derive(AsRecord)]
+    struct Wrapper<T>(SingleRecord<T>);
+
+    assert_eq!(
+        SingleRecord { val: 0.5 }.as_record(),
+        r#"record(ao, "$(P)Voltage") {
+  field(VAL, "0.5")
+}
+"#
+    );
+    assert_eq!(
+        MultipleRecords { mode: Mode::Fast }.as_record(),
+        r#"record(stringout, "$(P)Mode") {
+  field(VAL, "Fast")
+}
+"#
+    );
+    assert_eq!(
+        Wrapper(SingleRecord { val: 2 }).as_record(),
+        SingleRecord { val: 2 }.as_record()
+    );
+}
+
+#[test]
+fn test_as_record_no_quote() {
+    #[derive(AsRecord)]
+    #[record(rec_name = "$(P)Voltage", rec_type = "ao")]
+    struct SingleRecord {
+        #[record(field = "VAL")]
+        val: f64,
+        #[record(field = "FLNK", no_quote)]
+        flnk: &'static str,
+    }
+
+    assert_eq!(
+        SingleRecord {
+            val: 0.5,
+            flnk: "PP($(P)Current)"
+        }
+        .as_record(),
+        r#"record(ao, "$(P)Voltage") {
+  field(VAL, "0.5")
+  field(FLNK, PP($(P)Current))
+}
+"#
+    );
+}
+
+#[test]
+fn test_as_record_comment() {
+    #[derive(AsRecord)]
+    #[record(rec_name = "$(P)Voltage", rec_type = "ao")]
+    #[record(comment = "Generated from spreadsheet", comment = "Do not edit")]
+    struct SingleRecord {
+        #[record(field = "VAL", comment = "Initial value")]
+        val: f64,
+    }
+
+    #[derive(AsRecord)]
+    #[record(comment = "Channel")]
+    struct MultipleRecords {
+        #[record(rec_name = "$(P)Current", rec_type = "ao", field = "VAL")]
+        current: f64,
+        #[record(rec_name = "$(P)SlewRate", rec_type = "ao", field = "VAL")]
+        #[record(comment = "A/s")]
+        slew_rate: f64,
+    }
+
+    assert_eq!(
+        SingleRecord { val: 0.5 }.as_record(),
+        r#"# Generated from spreadsheet
+# Do not edit
+record(ao, "$(P)Voltage") {
+  # Initial value
+  field(VAL, "0.5")
+}
+"#
+    );
+    assert_eq!(
+        MultipleRecords {
+            current: 0.5,
+            slew_rate: 0.05
+        }
+        .as_record(),
+        r#"# Channel
+record(ao, "$(P)Current") {
+  field(VAL, "0.5")
+}
+# Channel
+# A/s
+record(ao, "$(P)SlewRate") {
+  field(VAL, "0.05")
+}
+"#
+    );
+}
+
+#[test]
+fn test_as_record_also_records() {
+    #[derive(AsRecord)]
+    #[record(rec_name = "$(P)Voltage", rec_type = "ao")]
+    #[record(also_rec_name = "$(P)Voltage-Link", also_rec_type = "stringin")]
+    struct SingleRecord {
+        #[record(field = "VAL")]
+        val: f64,
+    }
+
+    assert_eq!(
+        SingleRecord { val: 0.5 }.as_record(),
+        r#"record(ao, "$(P)Voltage") {
+  field(VAL, "0.5")
+}
+record(stringin, "$(P)Voltage-Link") {
+}
+"#
+    );
+}
+
+#[test]
+fn test_as_record_fmt_sep() {
+    #[derive(AsRecord)]
+    #[record(fmt_sep = "\n\n")]
+    struct MultipleRecords {
+        #[record(rec_name = "$(P)Voltage", rec_type = "ao", field = "VAL")]
+        voltage: f64,
+        #[record(rec_name = "$(P)Current", rec_type = "ao", field = "VAL")]
+        current: f64,
+    }
+
+    assert_eq!(
+        MultipleRecords {
+            voltage: 5.0,
+            current: 0.5
+        }
+        .as_record(),
+        r#"record(ao, "$(P)Voltage") {
+  field(VAL, "5")
+}
+
+record(ao, "$(P)Current") {
+  field(VAL, "0.5")
+}
+"#
+    );
+}
+
+#[test]
+fn test_as_record_strict() {
+    #[derive(AsRecord)]
+    #[record(strict)]
+    struct MultipleRecords {
+        #[record(rec_name = "$(P)Voltage", rec_type = "ao", field = "VAL")]
+        voltage: f64,
+        #[record(skip)]
+        #[allow(dead_code)]
+        note: String,
+    }
+
+    assert_eq!(
+        MultipleRecords {
+            voltage: 5.0,
+            note: String::new()
+        }
+        .as_record(),
+        r#"record(ao, "$(P)Voltage") {
+  field(VAL, "5")
+}
+"#
+    );
+}
+
+#[test]
+fn test_as_record_strict_type_check() {
+    // Every calc input is defined, so no warning is emitted
+    #[derive(AsRecord)]
+    #[record(
+        rec_name = "$(P)Sum",
+        rec_type = "calc",
+        calc_expr = "A+B",
+        strict_type_check
+    )]
+    struct Sum {
+        #[record(link_field = "INPA", link_modifier = "CP")]
+        input: &'static str,
+        #[record(field = "B")]
+        offset: f64,
+    }
+
+    assert_eq!(
+        Sum {
+            input: "$(P)Voltage",
+            offset: 1.5
+        }
+        .as_record(),
+        r#"record(calc, "$(P)Sum") {
+  field(CALC, "A+B")
+  field(INPA, "$(P)Voltage CP")
+  field(B, "1.5")
+}
+"#
+    );
+}
+
+#[test]
+fn test_as_record_repr_display_debug() {
+    #[derive(Debug)]
+    enum Mode {
+        Fast,
+    }
+
+    impl std::fmt::Display for Mode {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "FAST")
+        }
+    }
+
+    #[derive(AsRecord)]
+    #[record(rec_name = "$(P)Mode", rec_type = "stringout")]
+    struct SingleRecord {
+        #[record(field = "VAL", repr = "display")]
+        val: Mode,
+        #[record(field = "DESC", repr = "debug")]
+        desc: Mode,
+    }
+
+    assert_eq!(
+        SingleRecord {
+            val: Mode::Fast,
+            desc: Mode::Fast
+        }
+        .as_record(),
+        r#"record(stringout, "$(P)Mode") {
+  field(VAL, "FAST")
+  field(DESC, "Fast")
+}
+"#
+    );
+}
+
+#[test]
+fn test_as_record_width() {
+    #[derive(AsRecord)]
+    #[record(rec_name = "$(P)Name", rec_type = "stringout")]
+    struct SingleRecord {
+        #[record(field = "VAL", width = 8, align = "left")]
+        val: &'static str,
+        #[record(field = "DESC", width = 8, align = "right")]
+        desc: &'static str,
+        #[record(field = "PREC", width = 4, precision = 1)]
+        prec: f64,
+    }
+
+    assert_eq!(
+        SingleRecord {
+            val: "PS1",
+            desc: "PS1",
+            prec: 1.25
+        }
+        .as_record(),
+        r#"record(stringout, "$(P)Name") {
+  field(VAL, "PS1     ")
+  field(DESC, "     PS1")
+  field(PREC, " 1.2")
+}
+"#
+    );
+}
@@ -1,7 +1,7 @@
 use std::str::FromStr;
 
 use epics_gen::{DataType, FromXlsxData, FromXlsxRow, XlsxData};
-use epics_gen_macros::{FromXlsxFloat, FromXlsxString};
+use epics_gen_macros::{FromXlsxBool, FromXlsxDate, FromXlsxFloat, FromXlsxInt, FromXlsxString};
 
 #[derive(FromXlsxString, strum_macros::EnumString, PartialEq, Eq, Debug)]
 enum TestEnum {
@@ -43,6 +43,98 @@ fn test_from_xlsx_float() {
     assert!(matches!(result, Err(t) if t == epics_gen::ParseErrorKind::ValueMissing));
 }
 
+#[derive(FromXlsxInt, PartialEq, Debug)]
+struct TestInt(u8);
+
+impl TryFrom<i64> for TestInt {
+    type Error = std::num::TryFromIntError;
+
+    fn try_from(value: i64) -> Result<Self, Self::Error> {
+        Ok(Self(value.try_into()?))
+    }
+}
+
+#[test]
+fn test_from_xlsx_int() {
+    let result = TestInt::from_xlsx_data(XlsxData::Int(12));
+    assert!(matches!(result, Ok(t) if t == TestInt(12)));
+
+    let result = TestInt::from_xlsx_data(XlsxData::Float(12.0));
+    assert!(matches!(result, Ok(t) if t == TestInt(12)));
+
+    let result = TestInt::from_xlsx_data(XlsxData::Float(12.5));
+    assert!(matches!(result, Err(t) if t == epics_gen::ParseErrorKind::InvalidValue));
+
+    let result = TestInt::from_xlsx_data(XlsxData::Int(300));
+    assert!(matches!(result, Err(t) if t == epics_gen::ParseErrorKind::InvalidValue));
+
+    let result = TestInt::from_xlsx_data(XlsxData::String("42".to_string()));
+    assert!(matches!(result, Ok(t) if t == TestInt(42)));
+
+    let result = TestInt::from_xlsx_data(XlsxData::String("abc".to_string()));
+    assert!(matches!(result, Err(t) if t == epics_gen::ParseErrorKind::InvalidValue));
+
+    let result = TestInt::from_xlsx_data(XlsxData::Empty);
+    assert!(matches!(result, Err(t) if t == epics_gen::ParseErrorKind::ValueMissing));
+}
+
+#[derive(FromXlsxDate, PartialEq, Debug)]
+struct TestDays(f64);
+
+impl TryFrom<epics_gen::XlsxDateTime> for TestDays {
+    type Error = ();
+
+    fn try_from(value: epics_gen::XlsxDateTime) -> Result<Self, Self::Error> {
+        Ok(Self(value.as_f64()))
+    }
+}
+
+#[test]
+fn test_from_xlsx_date() {
+    let date = epics_gen::XlsxDateTime::new(45000.5, epics_gen::ExcelDateTimeType::DateTime, false);
+    let result = TestDays::from_xlsx_data(XlsxData::DateTime(date));
+    assert!(matches!(result, Ok(t) if t == TestDays(45000.5)));
+
+    let result = TestDays::from_xlsx_data(XlsxData::Float(45000.5));
+    assert!(matches!(result, Err(t) if t == epics_gen::ParseErrorKind::InvalidValue));
+
+    let result = TestDays::from_xlsx_data(XlsxData::Empty);
+    assert!(matches!(result, Err(t) if t == epics_gen::ParseErrorKind::ValueMissing));
+}
+
+#[derive(FromXlsxBool, PartialEq, Debug)]
+struct TestBool(bool);
+
+impl From<bool> for TestBool {
+    fn from(value: bool) -> Self {
+        Self(value)
+    }
+}
+
+#[test]
+fn test_from_xlsx_bool() {
+    let result = TestBool::from_xlsx_data(XlsxData::Bool(true));
+    assert!(matches!(result, Ok(t) if t == TestBool(true)));
+
+    for (cell, expected) in [
+        ("YES", true),
+        ("no", false),
+        ("True", true),
+        ("FALSE", false),
+        ("1", true),
+        ("0", false),
+    ] {
+        let result = TestBool::from_xlsx_data(XlsxData::String(cell.into()));
+        assert!(matches!(result, Ok(t) if t == TestBool(expected)));
+    }
+
+    let result = TestBool::from_xlsx_data(XlsxData::String("ThrowError".into()));
+    assert!(matches!(result, Err(t) if t == epics_gen::ParseErrorKind::InvalidValue));
+
+    let result = TestBool::from_xlsx_data(XlsxData::Empty);
+    assert!(matches!(result, Err(t) if t == epics_gen::ParseErrorKind::ValueMissing));
+}
+
 #[test]
 fn test_to_primitive() {
     let result = String::from_xlsx_data(XlsxData::String("Third".into()));
@@ -50,6 +142,49 @@ fn test_to_primitive() {
 
     let result = f64::from_xlsx_data(XlsxData::Float(0.1));
     assert!(matches!(result, Ok(t) if t == 0.1f64));
+
+    let result = i32::from_xlsx_data(XlsxData::Float(-3.0));
+    assert!(matches!(result, Ok(-3)));
+
+    let result = u64::from_xlsx_data(XlsxData::Int(42));
+    assert!(matches!(result, Ok(42)));
+
+    let result = usize::from_xlsx_data(XlsxData::String(" 7 ".into()));
+    assert!(matches!(result, Ok(7)));
+
+    let result = u32::from_xlsx_data(XlsxData::Float(-1.0));
+    assert!(matches!(result, Err(t) if t == epics_gen::ParseErrorKind::InvalidValue));
+
+    let result = i64::from_xlsx_data(XlsxData::Float(0.5));
+    assert!(matches!(result, Err(t) if t == epics_gen::ParseErrorKind::InvalidValue));
+
+    let result = i64::from_xlsx_data(XlsxData::Empty);
+    assert!(matches!(result, Err(t) if t == epics_gen::ParseErrorKind::ValueMissing));
+
+    // Out of range floats are not saturated to `i64::MAX`
+    let result = i64::from_xlsx_data(XlsxData::Float(1e20));
+    assert!(matches!(result, Err(t) if t == epics_gen::ParseErrorKind::InvalidValue));
+
+    let result = u64::from_xlsx_data(XlsxData::Float(1e20));
+    assert!(matches!(result, Err(t) if t == epics_gen::ParseErrorKind::InvalidValue));
+
+    let result = usize::from_xlsx_data(XlsxData::Float(-1e20));
+    assert!(matches!(result, Err(t) if t == epics_gen::ParseErrorKind::InvalidValue));
+
+    let result = u64::from_xlsx_data(XlsxData::String("18446744073709551615".into()));
+    assert!(matches!(result, Ok(u64::MAX)));
+
+    let result = i64::from_xlsx_data(XlsxData::String("18446744073709551615".into()));
+    assert!(matches!(result, Err(t) if t == epics_gen::ParseErrorKind::InvalidValue));
+
+    let result = bool::from_xlsx_data(XlsxData::String("Yes".into()));
+    assert!(matches!(result, Ok(true)));
+
+    let result = bool::from_xlsx_data(XlsxData::Bool(false));
+    assert!(matches!(result, Ok(false)));
+
+    let result = bool::from_xlsx_data(XlsxData::String("maybe".into()));
+    assert!(matches!(result, Err(t) if t == epics_gen::ParseErrorKind::InvalidValue));
 }
 
 #[test]
@@ -70,3 +205,439 @@ fn test_from_xlsx_row() {
 
     // TODO: Test Optional arguments
 }
+
+#[test]
+fn test_from_xlsx_row_skip() {
+    #[derive(FromXlsxRow, PartialEq, Debug)]
+    pub struct BuiltStruct {
+        pub enm: TestEnum,
+        #[xlsx(skip)]
+        pub comment: String,
+        pub flt: TestFloat,
+    }
+    let row: Vec<XlsxData> = vec![
+        XlsxData::String("Second".into()),
+        XlsxData::Float(std::f64::consts::PI),
+    ];
+    let parsed = BuiltStruct::from_xlsx_row(row, 0, "test_table").unwrap();
+
+    assert_eq!(parsed.enm, TestEnum::Second);
+    assert_eq!(parsed.comment, String::default());
+    assert_eq!(parsed.flt, TestFloat(std::f64::consts::PI));
+}
+
+#[test]
+fn test_from_xlsx_row_default() {
+    #[derive(FromXlsxRow, PartialEq, Debug)]
+    pub struct BuiltStruct {
+        #[xlsx(default = "TestEnum::First")]
+        pub enm: TestEnum,
+        #[xlsx(default = "TestFloat(-1.0)")]
+        pub flt: TestFloat,
+        #[xlsx(skip, default = "TestFloat(2.0)")]
+        pub skipped: TestFloat,
+    }
+    let row: Vec<XlsxData> = vec![XlsxData::Empty, XlsxData::Float(0.5)];
+    let parsed = BuiltStruct::from_xlsx_row(row, 0, "test_table").unwrap();
+
+    assert_eq!(parsed.enm, TestEnum::First);
+    assert_eq!(parsed.flt, TestFloat(0.5));
+    assert_eq!(parsed.skipped, TestFloat(2.0));
+
+    let row: Vec<XlsxData> = vec![XlsxData::String("Third".into()), XlsxData::Empty];
+    let parsed = BuiltStruct::from_xlsx_row(row, 0, "test_table").unwrap();
+
+    assert_eq!(parsed.enm, TestEnum::Third);
+    assert_eq!(parsed.flt, TestFloat(-1.0));
+}
+
+#[test]
+fn test_from_xlsx_row_tuple_struct() {
+    #[derive(FromXlsxRow, PartialEq, Debug)]
+    pub struct Voltage(TestFloat);
+
+    #[derive(FromXlsxRow, PartialEq, Debug)]
+    pub struct BuiltStruct(TestEnum, #[xlsx(skip)] String, TestFloat);
+
+    let parsed = Voltage::from_xlsx_row(vec![XlsxData::Float(0.5)], 0, "test_table").unwrap();
+    assert_eq!(parsed, Voltage(TestFloat(0.5)));
+
+    let row: Vec<XlsxData> = vec![XlsxData::String("First".into()), XlsxData::Float(0.5)];
+    let parsed = BuiltStruct::from_xlsx_row(row, 0, "test_table").unwrap();
+    assert_eq!(
+        parsed,
+        BuiltStruct(TestEnum::First, String::new(), TestFloat(0.5))
+    );
+}
+
+#[test]
+fn test_from_xlsx_row_unit_struct() {
+    #[derive(FromXlsxRow, PartialEq, Debug)]
+    pub struct Marker;
+
+    assert_eq!(Marker::COLUMN_COUNT, 0);
+    let row: Vec<XlsxData> = vec![XlsxData::String("First".into()), XlsxData::Float(0.5)];
+    assert_eq!(Marker::from_xlsx_row(row, 0, "test_table").unwrap(), Marker);
+    assert_eq!(
+        Marker::from_xlsx_row(vec![], 1, "test_table").unwrap(),
+        Marker
+    );
+}
+
+#[test]
+fn test_from_xlsx_row_tuple() {
+    let row: Vec<XlsxData> = vec![XlsxData::String("First".into()), XlsxData::Float(0.5)];
+    let parsed = <(TestEnum, TestFloat)>::from_xlsx_row(row, 0, "test_table").unwrap();
+    assert_eq!(parsed, (TestEnum::First, TestFloat(0.5)));
+
+    let row: Vec<XlsxData> = vec![
+        XlsxData::String("ps1".into()),
+        XlsxData::Float(2.0),
+        XlsxData::Bool(true),
+    ];
+    let parsed = <(String, u32, bool)>::from_xlsx_row(row, 0, "test_table").unwrap();
+    assert_eq!(parsed, ("ps1".to_string(), 2, true));
+    assert_eq!(<(String, u32, bool)>::COLUMN_COUNT, 3);
+
+    let row: Vec<XlsxData> = vec![XlsxData::String("First".into()), XlsxData::Empty];
+    let err = <(TestEnum, TestFloat)>::from_xlsx_row(row, 0, "test_table").unwrap_err();
+    assert_eq!(err.kind(), epics_gen::ParseErrorKind::ValueMissing);
+
+    let row: Vec<XlsxData> = vec![XlsxData::String("First".into())];
+    let err = <(TestEnum, TestFloat)>::from_xlsx_row(row, 0, "test_table").unwrap_err();
+    assert_eq!(err.kind(), epics_gen::ParseErrorKind::ColumnCountMismatch);
+}
+
+#[test]
+fn test_from_xlsx_row_column_index() {
+    #[derive(FromXlsxRow, PartialEq, Debug)]
+    pub struct BuiltStruct {
+        #[xlsx(column = 3)]
+        flt: TestFloat,
+        enm: TestEnum,
+    }
+
+    assert_eq!(BuiltStruct::COLUMN_COUNT, 4);
+    let row: Vec<XlsxData> = vec![
+        XlsxData::String("Second".into()),
+        XlsxData::Empty,
+        XlsxData::Empty,
+        XlsxData::Float(0.5),
+    ];
+    let parsed = BuiltStruct::from_xlsx_row(row, 0, "test_table").unwrap();
+    assert_eq!(
+        parsed,
+        BuiltStruct {
+            flt: TestFloat(0.5),
+            enm: TestEnum::Second,
+        }
+    );
+
+    let row: Vec<XlsxData> = vec![XlsxData::String("Second".into()), XlsxData::Float(0.5)];
+    let err = BuiltStruct::from_xlsx_row(row, 0, "test_table").unwrap_err();
+    assert_eq!(err.kind(), epics_gen::ParseErrorKind::ColumnCountMismatch);
+}
+
+#[test]
+fn test_from_xlsx_row_as_string() {
+    assert_eq!(
+        epics_gen::get_as_string(&XlsxData::Float(1.0)),
+        Some("1".to_string())
+    );
+    assert_eq!(
+        epics_gen::get_as_string(&XlsxData::Float(2.5)),
+        Some("2.5".to_string())
+    );
+    assert_eq!(
+        epics_gen::get_as_string(&XlsxData::Int(7)),
+        Some("7".to_string())
+    );
+    assert_eq!(epics_gen::get_as_string(&XlsxData::Empty), None);
+
+    #[derive(FromXlsxRow, PartialEq, Debug)]
+    pub struct BuiltStruct {
+        enm: TestEnum,
+        #[xlsx(as_string)]
+        value: String,
+    }
+
+    let row: Vec<XlsxData> = vec![XlsxData::String("First".into()), XlsxData::Float(1.0)];
+    let parsed = BuiltStruct::from_xlsx_row(row, 0, "test_table").unwrap();
+    assert_eq!(parsed.value, "1");
+
+    let row: Vec<XlsxData> = vec![
+        XlsxData::String("First".into()),
+        XlsxData::String("auto".into()),
+    ];
+    let parsed = BuiltStruct::from_xlsx_row(row, 0, "test_table").unwrap();
+    assert_eq!(parsed.value, "auto");
+
+    let row: Vec<XlsxData> = vec![XlsxData::String("First".into()), XlsxData::Empty];
+    let err = BuiltStruct::from_xlsx_row(row, 0, "test_table").unwrap_err();
+    assert_eq!(err.kind(), epics_gen::ParseErrorKind::ValueMissing);
+}
+
+#[test]
+fn test_from_xlsx_row_validate() {
+    #[derive(FromXlsxRow, PartialEq, Debug)]
+    pub struct BuiltStruct {
+        #[xlsx(validate = "(0.0..=5.0).contains(&val)")]
+        pub voltage: f64,
+        #[xlsx(validate = "val.0 > 0.0")]
+        pub current: Option<TestFloat>,
+    }
+
+    let row: Vec<XlsxData> = vec![XlsxData::Float(3.3), XlsxData::Empty];
+    let parsed = BuiltStruct::from_xlsx_row(row, 0, "test_table").unwrap();
+    assert_eq!(
+        parsed,
+        BuiltStruct {
+            voltage: 3.3,
+            current: None
+        }
+    );
+
+    let row: Vec<XlsxData> = vec![XlsxData::Float(12.0), XlsxData::Float(0.5)];
+    let err = BuiltStruct::from_xlsx_row(row, 0, "test_table").unwrap_err();
+    assert_eq!(err.kind(), epics_gen::ParseErrorKind::InvalidValue);
+
+    let row: Vec<XlsxData> = vec![XlsxData::Float(3.3), XlsxData::Float(-0.5)];
+    let err = BuiltStruct::from_xlsx_row(row, 0, "test_table").unwrap_err();
+    assert_eq!(err.kind(), epics_gen::ParseErrorKind::InvalidValue);
+}
+
+#[test]
+fn test_from_xlsx_row_vec() {
+    #[derive(FromXlsxRow, PartialEq, Debug)]
+    pub struct BuiltStruct {
+        pub name: String,
+        pub values: Vec<Option<f64>>,
+    }
+
+    let row: Vec<XlsxData> = vec![
+        XlsxData::String("PS1".to_owned()),
+        XlsxData::Float(1.0),
+        XlsxData::Empty,
+        XlsxData::Float(3.0),
+    ];
+    let parsed = BuiltStruct::from_xlsx_row(row, 0, "test_table").unwrap();
+    assert_eq!(
+        parsed,
+        BuiltStruct {
+            name: "PS1".to_owned(),
+            values: vec![Some(1.0), None, Some(3.0)]
+        }
+    );
+
+    let row: Vec<XlsxData> = vec![XlsxData::String("PS2".to_owned())];
+    let parsed = BuiltStruct::from_xlsx_row(row, 0, "test_table").unwrap();
+    assert!(parsed.values.is_empty());
+}
+
+#[test]
+fn test_from_xlsx_row_vec_trailing_empty() {
+    #[derive(FromXlsxRow, PartialEq, Debug)]
+    pub struct BuiltStruct {
+        pub name: String,
+        pub values: Vec<f64>,
+    }
+
+    // Rows are padded to the table width with empty cells
+    let row: Vec<XlsxData> = vec![
+        XlsxData::String("a".to_owned()),
+        XlsxData::Float(1.0),
+        XlsxData::Float(2.0),
+        XlsxData::Empty,
+        XlsxData::Empty,
+    ];
+    let parsed = BuiltStruct::from_xlsx_row(row, 0, "test_table").unwrap();
+    assert_eq!(parsed.values, vec![1.0, 2.0]);
+
+    let row: Vec<XlsxData> = vec![XlsxData::String("b".to_owned()), XlsxData::Empty];
+    let parsed = BuiltStruct::from_xlsx_row(row, 0, "test_table").unwrap();
+    assert!(parsed.values.is_empty());
+
+    // Empty cells between the values are still converted
+    let row: Vec<XlsxData> = vec![
+        XlsxData::String("c".to_owned()),
+        XlsxData::Float(1.0),
+        XlsxData::Empty,
+        XlsxData::Float(3.0),
+    ];
+    let err = BuiltStruct::from_xlsx_row(row, 0, "test_table").unwrap_err();
+    assert_eq!(err.kind(), epics_gen::ParseErrorKind::ValueMissing);
+}
+
+#[test]
+fn test_from_xlsx_row_remainder() {
+    #[derive(FromXlsxRow, PartialEq, Debug)]
+    pub struct BuiltStruct {
+        pub name: String,
+        pub extra: epics_gen::XlsxRowRemainder,
+    }
+
+    #[derive(FromXlsxRow, PartialEq, Debug)]
+    pub struct BuiltHashMap(TestEnum, std::collections::HashMap<String, XlsxData>);
+
+    assert_eq!(BuiltStruct::COLUMN_COUNT, 1);
+    let header = ["Name", "Gain", "Offset"].map(String::from);
+    let row: Vec<XlsxData> = vec![
+        XlsxData::String("PS1".to_owned()),
+        XlsxData::Float(1.5),
+        XlsxData::Empty,
+    ];
+    let parsed = BuiltStruct::from_xlsx_row_with_header(row, &header, 0, "test_table").unwrap();
+    assert_eq!(parsed.name, "PS1");
+    assert_eq!(parsed.extra.len(), 2);
+    assert_eq!(parsed.extra["Gain"], XlsxData::Float(1.5));
+    assert_eq!(parsed.extra["Offset"], XlsxData::Empty);
+
+    // Without the header, the cells are keyed by their column letters
+    let row: Vec<XlsxData> = vec![XlsxData::String("Third".into()), XlsxData::Int(3)];
+    let parsed = BuiltHashMap::from_xlsx_row(row, 0, "test_table").unwrap();
+    assert_eq!(parsed.0, TestEnum::Third);
+    assert_eq!(parsed.1["B"], XlsxData::Int(3));
+
+    let row: Vec<XlsxData> = vec![XlsxData::String("PS1".to_owned())];
+    let parsed = BuiltStruct::from_xlsx_row(row, 0, "test_table").unwrap();
+    assert!(parsed.extra.is_empty());
+}
+
+#[test]
+fn test_from_xlsx_row_remainder_skips_read_columns() {
+    #[derive(FromXlsxRow, PartialEq, Debug)]
+    pub struct BuiltStruct {
+        pub name: String,
+        #[xlsx(column = 3)]
+        pub current: f64,
+        #[xlsx(column = "Gain")]
+        pub gain: f64,
+        pub extra: epics_gen::XlsxRowRemainder,
+    }
+
+    let header = ["Name", "Gain", "Offset", "Current", "Limit"].map(String::from);
+    let row: Vec<XlsxData> = vec![
+        XlsxData::String("PS1".to_owned()),
+        XlsxData::Float(1.5),
+        XlsxData::Float(0.1),
+        XlsxData::Float(2.0),
+        XlsxData::Float(5.0),
+    ];
+    let parsed = BuiltStruct::from_xlsx_row_with_header(row, &header, 0, "test_table").unwrap();
+    assert_eq!(parsed.current, 2.0);
+    assert_eq!(parsed.gain, 1.5);
+    // Only the columns that aren't read by other members are in the remainder
+    let mut extra: Vec<&String> = parsed.extra.keys().collect();
+    extra.sort();
+    assert_eq!(extra, ["Limit", "Offset"]);
+}
+
+#[test]
+fn test_from_xlsx_row_parse_with() {
+    #[derive(PartialEq, Debug)]
+    pub struct Range {
+        min: f64,
+        max: f64,
+    }
+
+    /// Combines two adjacent cells into a range.
+    fn parse_range(
+        row: &mut Vec<XlsxData>,
+        row_num: usize,
+        table_name: &str,
+    ) -> Result<Range, epics_gen::ParseError> {
+        let error = |kind| {
+            epics_gen::ParseError::new_in_table(
+                kind,
+                epics_gen::XlsxCell::new((row_num as u32, 1), XlsxData::Empty),
+                table_name,
+            )
+        };
+        if row.len() < 2 {
+            return Err(error(epics_gen::ParseErrorKind::ValueMissing));
+        }
+        let mut cells = row.drain(..2).map(f64::from_xlsx_data);
+        Ok(Range {
+            min: cells.next().unwrap().map_err(error)?,
+            max: cells.next().unwrap().map_err(error)?,
+        })
+    }
+
+    #[derive(FromXlsxRow, PartialEq, Debug)]
+    pub struct BuiltStruct {
+        enm: TestEnum,
+        #[xlsx(parse_with = "parse_range")]
+        range: Range,
+        flt: TestFloat,
+    }
+
+    let row: Vec<XlsxData> = vec![
+        XlsxData::String("Third".into()),
+        XlsxData::Float(-1.0),
+        XlsxData::Float(1.0),
+        XlsxData::Float(0.5),
+    ];
+    let parsed = BuiltStruct::from_xlsx_row(row, 0, "test_table").unwrap();
+    assert_eq!(
+        parsed,
+        BuiltStruct {
+            enm: TestEnum::Third,
+            range: Range {
+                min: -1.0,
+                max: 1.0
+            },
+            flt: TestFloat(0.5),
+        }
+    );
+
+    let row: Vec<XlsxData> = vec![XlsxData::String("Third".into()), XlsxData::Float(-1.0)];
+    let err = BuiltStruct::from_xlsx_row(row, 0, "test_table").unwrap_err();
+    assert_eq!(err.kind(), epics_gen::ParseErrorKind::ValueMissing);
+}
+
+#[test]
+fn test_from_xlsx_row_missing_cell() {
+    #[derive(FromXlsxRow, PartialEq, Debug)]
+    pub struct BuiltStruct {
+        pub enm: TestEnum,
+        pub flt: TestFloat,
+        pub opt: Option<TestFloat>,
+    }
+
+    assert_eq!(BuiltStruct::COLUMN_COUNT, 3);
+
+    let row: Vec<XlsxData> = vec![XlsxData::String("First".into())];
+    let err = BuiltStruct::from_xlsx_row(row, 3, "test_table").unwrap_err();
+    assert_eq!(err.kind(), epics_gen::ParseErrorKind::ColumnCountMismatch);
+
+    let row: Vec<XlsxData> = vec![XlsxData::String("First".into()), XlsxData::Float(0.1)];
+    let err = BuiltStruct::from_xlsx_row(row, 3, "test_table").unwrap_err();
+    assert_eq!(err.kind(), epics_gen::ParseErrorKind::ColumnCountMismatch);
+
+    let row: Vec<XlsxData> = vec![
+        XlsxData::String("First".into()),
+        XlsxData::Float(0.1),
+        XlsxData::Empty,
+    ];
+    let parsed = BuiltStruct::from_xlsx_row(row, 3, "test_table").unwrap();
+    assert_eq!(parsed.opt, None);
+}
+
+#[test]
+fn test_from_xlsx_row_error_display() {
+    #[derive(FromXlsxRow, PartialEq, Debug)]
+    pub struct BuiltStruct {
+        pub enm: TestEnum,
+        pub flt: [TestFloat; 27],
+        pub last: TestFloat,
+    }
+
+    let mut row: Vec<XlsxData> = vec![XlsxData::String("First".into())];
+    row.extend((0..27).map(|_| XlsxData::Float(0.1)));
+    row.push(XlsxData::String("NaN".into()));
+    let err = BuiltStruct::from_xlsx_row(row, 3, "test_table").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Value is missing, Table: test_table, Row: 4, Col: AC, Value: NaN "
+    );
+}
@@ -1,6 +1,7 @@
-use epics_gen::{DataType, FromXlsxData, ParseError, ParserBuilder};
-use epics_gen_macros::{FromXlsxFloat, FromXlsxRow, FromXlsxString};
-use regex::Regex;
+use epics_gen::{
+    DataType, FromXlsxData, FromXlsxRow, ParseErrorKind, ParserBuilder, Regex, XlsxData,
+};
+use epics_gen_macros::{FromXlsxFloat, FromXlsxString};
 #[derive(FromXlsxString, strum_macros::EnumString, PartialEq, Eq, Debug)]
 enum RowId {
     First,
@@ -102,6 +103,278 @@ fn test_parser1_array() {
     }
 }
 
+#[test]
+fn test_parser1_open() {
+    #[derive(FromXlsxRow, Debug, PartialEq)]
+    struct TargetStruct {
+        row_id: RowId,
+        float1: TestFloat,
+        float2: TestFloat,
+    }
+
+    let parser = ParserBuilder::open("tests/test_parser1.xlsx")
+        .expect("xlsx file for this test is missing!")
+        .add_sheet("Sheet1")
+        .expect("Sheet1 does not exist.")
+        .add_table("test_table_1")
+        .expect("test_table_1 table does not exist.")
+        .build();
+
+    let parsed: Vec<TargetStruct> = parser.parse().unwrap();
+    assert_eq!(parsed.len(), 4);
+
+    assert!(ParserBuilder::open("tests/missing.xlsx").is_err());
+}
+
+#[cfg(feature = "config")]
+#[test]
+fn test_parser1_from_config() {
+    #[derive(FromXlsxRow, Debug, PartialEq)]
+    struct TargetStruct {
+        row_id: RowId,
+        float1: TestFloat,
+        float2: TestFloat,
+    }
+
+    let parser = ParserBuilder::from_config("tests/test_config.toml")
+        .expect("configuration file for this test is missing!")
+        .build();
+
+    let parsed: Vec<TargetStruct> = parser.parse().unwrap();
+    assert_eq!(parsed.len(), 4);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_parse_error_serde() {
+    use epics_gen::{ParseError, XlsxCell};
+    use std::error::Error;
+
+    let err = ParseError::new_in_table(
+        ParseErrorKind::InvalidValue,
+        XlsxCell::new((2, 1), XlsxData::Float(1.5)),
+        "test_table_1",
+    )
+    .with_source("value out of range");
+
+    let json = serde_json::to_string(&err).unwrap();
+    let deserialized: ParseError = serde_json::from_str(&json).unwrap();
+    assert_eq!(deserialized.kind(), ParseErrorKind::InvalidValue);
+    assert_eq!(deserialized.to_string(), err.to_string());
+    assert_eq!(
+        deserialized.source().unwrap().to_string(),
+        "value out of range"
+    );
+
+    let err = ParseError::invalid_sheet("Sheet3");
+    let deserialized: ParseError =
+        serde_json::from_str(&serde_json::to_string(&err).unwrap()).unwrap();
+    assert_eq!(
+        deserialized.to_string(),
+        "Invalid sheet name, Sheet: Sheet3"
+    );
+    assert!(deserialized.source().is_none());
+}
+
+#[test]
+fn test_parser1_sheet_order() {
+    #[derive(FromXlsxRow, Debug, PartialEq)]
+    struct TargetStruct {
+        row_id: RowId,
+        float1: TestFloat,
+        float2: TestFloat,
+    }
+
+    let parser = ParserBuilder::open("tests/test_parser1.xlsx")
+        .expect("xlsx file for this test is missing!")
+        .add_sheet("Sheet2")
+        .expect("Sheet2 does not exist.")
+        .add_sheet("Sheet1")
+        .expect("Sheet1 does not exist.")
+        .add_tables(Regex::new(r#"test_table_\d"#).unwrap())
+        .expect("test tables do not exist.")
+        .build();
+
+    // Sheets are parsed in the order they were added
+    let parsed: Vec<TargetStruct> = parser.parse().unwrap();
+    let floats: Vec<f64> = parsed.iter().map(|row| row.float1.0).collect();
+    assert_eq!(floats, vec![21.1, 22.2, 23.3, 24.4, 0.23, 1.23, 2.23, 3.23]);
+}
+
+#[test]
+fn test_parser1_sheet_and_table_names() {
+    let builder = ParserBuilder::open("tests/test_parser1.xlsx")
+        .expect("xlsx file for this test is missing!");
+
+    assert_eq!(builder.sheet_names(), vec!["Sheet1", "Sheet2"]);
+    assert_eq!(builder.table_names("Sheet1"), vec!["test_table_1"]);
+    assert_eq!(builder.table_names("Sheet2"), vec!["test_table_2"]);
+    assert!(builder.table_names("Sheet3").is_empty());
+}
+
+#[test]
+fn test_parser1_parse_sheet() {
+    #[derive(FromXlsxRow, Debug, PartialEq)]
+    struct TargetStruct {
+        row_id: RowId,
+        float1: TestFloat,
+        float2: TestFloat,
+    }
+
+    let build = || {
+        ParserBuilder::open("tests/test_parser1.xlsx")
+            .expect("xlsx file for this test is missing!")
+            .add_sheets(Regex::new(r#"Sheet\d"#).unwrap())
+            .expect("test sheets do not exist.")
+            .add_tables(Regex::new(r#"test_table_\d"#).unwrap())
+            .expect("test tables do not exist.")
+            .build()
+    };
+
+    let parsed: Vec<TargetStruct> = build().parse_sheet("Sheet2").unwrap();
+    let floats: Vec<f64> = parsed.iter().map(|row| row.float1.0).collect();
+    assert_eq!(floats, vec![21.1, 22.2, 23.3, 24.4]);
+
+    let err = build().parse_sheet::<TargetStruct>("Sheet3").unwrap_err();
+    assert_eq!(err.kind(), ParseErrorKind::InvalidTableName);
+}
+
+#[test]
+fn test_parser1_flat_map_parse() {
+    #[derive(FromXlsxRow, Debug, PartialEq)]
+    struct TargetStruct {
+        row_id: RowId,
+        float1: TestFloat,
+        float2: TestFloat,
+    }
+
+    let parser = ParserBuilder::open("tests/test_parser1.xlsx")
+        .expect("xlsx file for this test is missing!")
+        .add_sheet("Sheet1")
+        .expect("Sheet1 does not exist.")
+        .add_table("test_table_1")
+        .expect("test_table_1 does not exist.")
+        .build();
+
+    let floats: Vec<f64> = parser
+        .flat_map_parse(|row: TargetStruct| match row.row_id {
+            RowId::Second => vec![],
+            _ => vec![row.float1.0, row.float2.0],
+        })
+        .unwrap();
+    assert_eq!(floats, vec![0.23, 0.333, 2.23, 2.333, 3.23, 3.333]);
+}
+
+#[test]
+fn test_open_workbook_from_bytes() {
+    let bytes = include_bytes!("test_parser1.xlsx");
+    let mut workbook = epics_gen::open_workbook_from_bytes(bytes).unwrap();
+    workbook.load_tables().unwrap();
+    assert!(workbook.table_by_name("test_table_1").is_ok());
+
+    assert!(epics_gen::open_workbook_from_bytes(b"not a workbook").is_err());
+}
+
+#[test]
+fn test_parser1_from_bytes() {
+    #[derive(FromXlsxRow, Debug, PartialEq)]
+    struct TargetStruct {
+        row_id: RowId,
+        float1: TestFloat,
+        float2: TestFloat,
+    }
+
+    let mut workbook = epics_gen::open_workbook_from_bytes(include_bytes!("test_parser1.xlsx"))
+        .expect("xlsx file for this test is missing!");
+    let parser = ParserBuilder::new(&mut workbook)
+        .add_sheet("Sheet2")
+        .expect("Sheet2 does not exist.")
+        .add_table("test_table_2")
+        .expect("test_table_2 does not exist.")
+        .build();
+
+    let parsed: Vec<TargetStruct> = parser.parse().unwrap();
+    let floats: Vec<f64> = parsed.iter().map(|row| row.float1.0).collect();
+    assert_eq!(floats, vec![21.1, 22.2, 23.3, 24.4]);
+}
+
+#[test]
+fn test_parser1_add_sheet_with_tables() {
+    #[derive(FromXlsxRow, Debug, PartialEq)]
+    struct TargetStruct {
+        row_id: RowId,
+        float1: TestFloat,
+        float2: TestFloat,
+    }
+
+    let mut workbook: epics_gen::XlsxWorkbook = epics_gen::open_workbook("tests/test_parser1.xlsx")
+        .expect("xlsx file for this test is missing!");
+
+    let parser = ParserBuilder::new(&mut workbook)
+        .add_sheet_with_tables("Sheet2", ["test_table_2"])
+        .expect("test_table_2 does not exist in Sheet2.")
+        .build();
+    let parsed: Vec<TargetStruct> = parser.parse().unwrap();
+    assert_eq!(parsed.len(), 4);
+
+    let builder =
+        ParserBuilder::new(&mut workbook).add_sheet_with_tables("Sheet3", ["test_table_1"]);
+    assert_eq!(
+        builder.err().map(|err| err.kind()),
+        Some(ParseErrorKind::InvalidSheetName)
+    );
+
+    let builder = ParserBuilder::new(&mut workbook)
+        .add_sheet_with_tables("Sheet1", ["test_table_1", "test_table_2"]);
+    assert_eq!(
+        builder.err().map(|err| err.kind()),
+        Some(ParseErrorKind::InvalidTableName)
+    );
+}
+
+#[test]
+fn test_parser1_parse_with_table_names() {
+    #[derive(FromXlsxRow, Debug, PartialEq)]
+    struct TargetStruct {
+        row_id: RowId,
+        float1: TestFloat,
+        float2: TestFloat,
+    }
+
+    let parser = ParserBuilder::open("tests/test_parser1.xlsx")
+        .expect("xlsx file for this test is missing!")
+        .add_sheets(Regex::new(r#"Sheet\d"#).unwrap())
+        .expect("test sheets do not exist.")
+        .add_tables(Regex::new(r#"test_table_\d"#).unwrap())
+        .expect("test tables do not exist.")
+        .build();
+
+    let parsed: Vec<epics_gen::WithTableName<TargetStruct>> =
+        parser.parse_with_table_names().unwrap();
+    assert_eq!(parsed.len(), 8);
+    assert_eq!(parsed[0].table, "test_table_1");
+    assert_eq!(parsed[0].row_id, RowId::First);
+    assert_eq!(parsed[4].table, "test_table_2");
+    assert_eq!(parsed[4].float1, TestFloat(21.1));
+}
+
+#[test]
+fn test_matched_names() {
+    let builder = ParserBuilder::open("tests/test_parser1.xlsx")
+        .expect("xlsx file for this test is missing!")
+        .add_sheets(Regex::new(r#"Sheet\d"#).unwrap())
+        .expect("No sheet matches the pattern.")
+        .add_sheet("Sheet1")
+        .expect("Sheet1 does not exist.")
+        .add_tables(Regex::new(r#"test_table_\d"#).unwrap())
+        .expect("No table matches the pattern.");
+
+    assert_eq!(builder.matched_sheets(), vec!["Sheet1", "Sheet2"]);
+    assert_eq!(builder.matched_tables("Sheet1"), vec!["test_table_1"]);
+    assert_eq!(builder.matched_tables("Sheet2"), vec!["test_table_2"]);
+    assert!(builder.matched_tables("Sheet3").is_empty());
+}
+
 #[test]
 fn test_invalid_names() {
     let mut workbook: epics_gen::XlsxWorkbook = epics_gen::open_workbook("tests/test_parser1.xlsx")
@@ -109,10 +382,18 @@ fn test_invalid_names() {
 
     let builder = ParserBuilder::new(&mut workbook).add_sheet("Unexisting_Sheet");
     assert!(builder.is_err());
+    assert_eq!(
+        builder.err().map(|err| err.kind()),
+        Some(ParseErrorKind::InvalidSheetName)
+    );
 
     let builder =
         ParserBuilder::new(&mut workbook).add_sheets(Regex::new(r#"$SomePattern\d+^"#).unwrap());
     assert!(builder.is_err());
+    assert_eq!(
+        builder.err().map(|err| err.kind()),
+        Some(ParseErrorKind::InvalidSheetName)
+    );
 
     let builder = ParserBuilder::new(&mut workbook)
         .add_sheet("Sheet1")
@@ -125,4 +406,357 @@ fn test_invalid_names() {
         .expect("Sheet1 exists.")
         .add_tables(Regex::new(r#"$TablePattern\d+"#).unwrap());
     assert!(builder.is_err());
+    assert_eq!(
+        builder.err().map(|err| err.kind()),
+        Some(ParseErrorKind::InvalidTableName)
+    );
+
+    // test_table_2 matches the pattern, but is not in Sheet1
+    let builder = ParserBuilder::new(&mut workbook)
+        .add_sheet("Sheet1")
+        .expect("Sheet1 exists.")
+        .add_tables(Regex::new(r#"test_table_2"#).unwrap());
+    assert_eq!(
+        builder.err().map(|err| err.kind()),
+        Some(ParseErrorKind::InvalidTableName)
+    );
+
+    // test_table_2 exists, but not in Sheet1
+    let builder = ParserBuilder::new(&mut workbook)
+        .add_sheet("Sheet1")
+        .expect("Sheet1 exists.")
+        .add_table("test_table_2");
+    assert_eq!(
+        builder.err().map(|err| err.kind()),
+        Some(ParseErrorKind::InvalidTableName)
+    );
+}
+
+#[test]
+fn test_workbook_error_source() {
+    let err: epics_gen::ParseError =
+        epics_gen::open_workbook::<epics_gen::XlsxWorkbook, _>("tests/missing.xlsx")
+            .map(|_| ())
+            .unwrap_err()
+            .into();
+    assert_eq!(err.kind(), ParseErrorKind::Workbook);
+    assert!(std::error::Error::source(&err).is_some());
+}
+
+#[test]
+fn test_validate() {
+    let mut workbook: epics_gen::XlsxWorkbook = epics_gen::open_workbook("tests/test_parser1.xlsx")
+        .expect("xlsx file for this test is missing!");
+
+    #[derive(FromXlsxRow, Debug, PartialEq)]
+    struct TargetStruct {
+        row_id: RowId,
+        float1: TestFloat,
+        float2: TestFloat,
+    }
+
+    // Needs one more column than the test tables have
+    #[derive(FromXlsxRow, Debug, PartialEq)]
+    struct WideStruct {
+        row_id: RowId,
+        float1: TestFloat,
+        float2: TestFloat,
+        float3: TestFloat,
+    }
+
+    let mut builder = ParserBuilder::new(&mut workbook)
+        .add_sheets(Regex::new(r#"Sheet\d"#).unwrap())
+        .expect("Sheets exist.")
+        .add_table("test_table_1")
+        .expect("test_table_1 exists.")
+        .add_table("test_table_2")
+        .expect("test_table_2 exists.");
+    assert!(builder.validate::<TargetStruct>().is_ok());
+
+    let errors = builder.validate::<WideStruct>().unwrap_err();
+    assert_eq!(errors.len(), 2);
+    assert!(errors
+        .iter()
+        .all(|err| err.kind() == ParseErrorKind::ColumnCountMismatch));
+    assert!(errors[0].to_string().contains("test_table_1"));
+
+    // The header (`RowId`, `Float1`, `Float2`) doesn't match the member names in strict mode
+    #[derive(FromXlsxRow, Debug, PartialEq)]
+    struct RenamedStruct {
+        id: RowId,
+        float1: TestFloat,
+        float2: TestFloat,
+    }
+
+    let mut builder = builder.strict_column_order(true);
+    assert!(builder.validate::<TargetStruct>().is_ok());
+    let errors = builder.validate::<RenamedStruct>().unwrap_err();
+    assert_eq!(errors.len(), 2);
+    assert!(errors
+        .iter()
+        .all(|err| err.kind() == ParseErrorKind::ColumnOrderMismatch));
+}
+
+#[test]
+fn test_parser1_parse_with_header() {
+    #[derive(FromXlsxRow, Debug, PartialEq)]
+    struct TargetStruct {
+        row_id: RowId,
+        float1: TestFloat,
+        float2: TestFloat,
+    }
+
+    let mut workbook: epics_gen::XlsxWorkbook = epics_gen::open_workbook("tests/test_parser1.xlsx")
+        .expect("xlsx file for this test is missing!");
+
+    let parser = epics_gen::ParserBuilder::new(&mut workbook)
+        .add_sheet("Sheet1")
+        .expect("Sheet1 does not exist.")
+        .add_table("test_table_1")
+        .expect("test_table_1 table does not exist.")
+        .build();
+
+    let (header, parsed): (_, Vec<TargetStruct>) = parser.parse_with_header().unwrap();
+
+    // The header holds the column names, every data row is parsed
+    assert_eq!(
+        header,
+        vec![
+            XlsxData::String("RowId".into()),
+            XlsxData::String("Float1".into()),
+            XlsxData::String("Float2".into()),
+        ]
+    );
+    assert_eq!(parsed.len(), 4);
+    assert_eq!(parsed[0].row_id, RowId::First);
+    assert_eq!(parsed[0].float1, TestFloat(0.23));
+}
+
+#[test]
+fn test_parser1_column_count_mismatch() {
+    #[derive(FromXlsxRow, Debug, PartialEq)]
+    struct TargetStruct {
+        row_id: RowId,
+        floats: [TestFloat; 3],
+    }
+
+    let mut workbook: epics_gen::XlsxWorkbook = epics_gen::open_workbook("tests/test_parser1.xlsx")
+        .expect("xlsx file for this test is missing!");
+
+    let parser = epics_gen::ParserBuilder::new(&mut workbook)
+        .add_sheet("Sheet1")
+        .expect("Sheet1 does not exist.")
+        .add_table("test_table_1")
+        .expect("test_table_1 table does not exist.")
+        .build();
+
+    let err = parser.parse::<TargetStruct>().unwrap_err();
+    assert_eq!(err.kind(), ParseErrorKind::ColumnCountMismatch);
+}
+
+#[test]
+fn test_parser1_parse_all() {
+    #[derive(FromXlsxRow, Debug, PartialEq)]
+    struct TargetStruct {
+        row_id: RowId,
+        #[xlsx(validate = "val.0 < 2.0")]
+        float1: TestFloat,
+        float2: TestFloat,
+    }
+
+    let mut workbook: epics_gen::XlsxWorkbook = epics_gen::open_workbook("tests/test_parser1.xlsx")
+        .expect("xlsx file for this test is missing!");
+
+    let parser = epics_gen::ParserBuilder::new(&mut workbook)
+        .add_sheet("Sheet1")
+        .expect("Sheet1 does not exist.")
+        .add_table("test_table_1")
+        .expect("test_table_1 table does not exist.")
+        .build();
+
+    let (parsed, errors): (Vec<TargetStruct>, _) = parser.parse_all();
+
+    assert_eq!(parsed.len(), 2);
+    assert_eq!(errors.len(), 2);
+    assert!(errors
+        .iter()
+        .all(|err| err.kind() == ParseErrorKind::InvalidValue));
+}
+
+#[test]
+fn test_parser1_column_header() {
+    #[derive(FromXlsxRow, Debug, PartialEq)]
+    struct TargetStruct {
+        row_id: RowId,
+        #[xlsx(column = "Float2")]
+        float2: TestFloat,
+        float1: TestFloat,
+    }
+
+    let mut workbook: epics_gen::XlsxWorkbook = epics_gen::open_workbook("tests/test_parser1.xlsx")
+        .expect("xlsx file for this test is missing!");
+
+    let parser = epics_gen::ParserBuilder::new(&mut workbook)
+        .add_sheet("Sheet1")
+        .expect("Sheet1 does not exist.")
+        .add_table("test_table_1")
+        .expect("test_table_1 table does not exist.")
+        .build();
+
+    let parsed: Vec<TargetStruct> = parser.parse().unwrap();
+
+    assert_eq!(
+        parsed[0],
+        TargetStruct {
+            row_id: RowId::First,
+            float2: 0.333.into(),
+            float1: 0.23.into(),
+        }
+    );
+
+    let err = TargetStruct::from_xlsx_row(
+        vec![
+            XlsxData::String("First".into()),
+            XlsxData::Float(0.23),
+            XlsxData::Float(0.333),
+        ],
+        0,
+        "test_table_1",
+    )
+    .unwrap_err();
+    assert_eq!(err.kind(), ParseErrorKind::InvalidColumnName);
+}
+
+#[test]
+fn test_parser1_strict_column_order() {
+    #[derive(FromXlsxRow, Debug, PartialEq)]
+    struct TargetStruct {
+        row_id: RowId,
+        float1: TestFloat,
+        float2: TestFloat,
+    }
+
+    #[derive(FromXlsxRow, Debug, PartialEq)]
+    struct SwappedStruct {
+        row_id: RowId,
+        float2: TestFloat,
+        float1: TestFloat,
+    }
+
+    assert_eq!(TargetStruct::COLUMN_NAMES, ["row_id", "float1", "float2"]);
+
+    let mut workbook: epics_gen::XlsxWorkbook = epics_gen::open_workbook("tests/test_parser1.xlsx")
+        .expect("xlsx file for this test is missing!");
+
+    let parser = ParserBuilder::new(&mut workbook)
+        .add_sheet("Sheet1")
+        .expect("Sheet1 does not exist.")
+        .add_table("test_table_1")
+        .expect("test_table_1 table does not exist.")
+        .strict_column_order(true)
+        .build();
+    let parsed: Vec<TargetStruct> = parser.parse().unwrap();
+    assert_eq!(parsed.len(), 4);
+
+    let parser = ParserBuilder::new(&mut workbook)
+        .add_sheet("Sheet1")
+        .expect("Sheet1 does not exist.")
+        .add_table("test_table_1")
+        .expect("test_table_1 table does not exist.")
+        .strict_column_order(true)
+        .build();
+    let err = parser.parse::<SwappedStruct>().unwrap_err();
+    assert_eq!(err.kind(), ParseErrorKind::ColumnOrderMismatch);
+    assert!(err.to_string().contains("Column: float2"));
+}
+
+#[test]
+fn test_parser1_rename() {
+    #[derive(FromXlsxRow, Debug, PartialEq)]
+    struct TargetStruct {
+        #[xlsx(rename = "Float1")]
+        first_float: TestFloat,
+        #[xlsx(rename = "RowId")]
+        row_id: RowId,
+    }
+
+    let mut workbook: epics_gen::XlsxWorkbook = epics_gen::open_workbook("tests/test_parser1.xlsx")
+        .expect("xlsx file for this test is missing!");
+
+    let parser = epics_gen::ParserBuilder::new(&mut workbook)
+        .add_sheet("Sheet1")
+        .expect("Sheet1 does not exist.")
+        .add_table("test_table_1")
+        .expect("test_table_1 table does not exist.")
+        .build();
+
+    let parsed: Vec<TargetStruct> = parser.parse().unwrap();
+
+    assert_eq!(
+        parsed[1],
+        TargetStruct {
+            first_float: 1.23.into(),
+            row_id: RowId::Second,
+        }
+    );
+}
+
+#[test]
+fn test_parse_error_context() {
+    let cell = || epics_gen::XlsxCell::new((1, 2), XlsxData::Empty);
+
+    let err = epics_gen::ParseError::new_in_sheet(ParseErrorKind::ValueMissing, cell(), "Sheet1");
+    assert!(err.to_string().contains("Sheet: Sheet1"));
+
+    let err =
+        epics_gen::ParseError::new_in_table(ParseErrorKind::ValueMissing, cell(), "test_table_1");
+    assert!(err.to_string().contains("Table: test_table_1"));
+
+    let context = epics_gen::XlsxContext::column("Float1");
+    assert_eq!(context.kind(), epics_gen::ContextKind::Column);
+    let err = epics_gen::ParseError::new_in_context(ParseErrorKind::InvalidValue, cell(), context);
+    assert!(err.to_string().contains("Column: Float1"));
+}
+
+#[test]
+fn test_mock_table() {
+    #[derive(FromXlsxRow, Debug, PartialEq)]
+    struct TargetStruct {
+        row_id: RowId,
+        #[xlsx(column = "Float2")]
+        second_float: TestFloat,
+    }
+
+    let table = epics_gen::MockTable::new("mock_table")
+        .header(["RowId", "Float1", "Float2"])
+        .row([XlsxData::from("First"), 0.5.into(), 1.5.into()])
+        .row([XlsxData::from("Second"), 2.5.into(), 3.5.into()]);
+
+    let parsed: Vec<TargetStruct> = table.parse().unwrap();
+    assert_eq!(
+        parsed,
+        vec![
+            TargetStruct {
+                row_id: RowId::First,
+                second_float: 1.5.into(),
+            },
+            TargetStruct {
+                row_id: RowId::Second,
+                second_float: 3.5.into(),
+            },
+        ]
+    );
+
+    let table = table.row([XlsxData::from("Fifth"), 0.0.into(), 0.0.into()]);
+    let err = table.parse::<TargetStruct>().unwrap_err();
+    assert_eq!(err.kind(), ParseErrorKind::InvalidValue);
+    assert!(err.to_string().contains("Table: mock_table, Row: 3"));
+
+    let rows = epics_gen::MockTable::new("mock_table")
+        .row(Vec::<XlsxData>::new())
+        .parse_all_rows::<TargetStruct>();
+    assert_eq!(
+        rows[0].as_ref().unwrap_err().kind(),
+        ParseErrorKind::ColumnCountMismatch
+    );
 }
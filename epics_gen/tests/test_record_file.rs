@@ -0,0 +1,153 @@
+use epics_gen::{db_record_name_from_str, validate_db_string, AsRecord, RecordFile};
+
+#[derive(AsRecord)]
+#[record(rec_name = "$(P)Voltage", rec_type = "ao")]
+struct Voltage {
+    #[record(field = "VAL")]
+    val: f64,
+}
+
+#[derive(AsRecord)]
+struct Channel {
+    #[record(rec_name = "$(P)Current", rec_type = "ao", field = "VAL")]
+    current: f64,
+    #[record(rec_name = "$(P)SlewRate", rec_type = "ao", field = "VAL")]
+    slew_rate: f64,
+}
+
+#[test]
+fn test_record_file() {
+    let mut file = RecordFile::new();
+    file.push(Voltage { val: 0.5 });
+    file.extend([Channel {
+        current: 0.5,
+        slew_rate: 0.05,
+    }]);
+
+    assert_eq!(file.records().len(), 3);
+    assert_eq!(
+        file.to_string(),
+        r#"record(ao, "$(P)Voltage") {
+  field(VAL, "0.5")
+}
+record(ao, "$(P)Current") {
+  field(VAL, "0.5")
+}
+record(ao, "$(P)SlewRate") {
+  field(VAL, "0.05")
+}
+"#
+    );
+
+    let path = std::env::temp_dir().join("epics_gen_test_record_file.db");
+    file.write_to_file(&path).unwrap();
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), file.to_string());
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn test_record_file_dedup() {
+    let mut file = RecordFile::new();
+    file.push(Voltage { val: 0.5 });
+    file.push(Voltage { val: 0.5 });
+    file.dedup_by_name().unwrap();
+    assert_eq!(file.records().len(), 1);
+
+    file.push(Voltage { val: 1.5 });
+    let err = file.dedup_by_name().unwrap_err();
+    assert_eq!(err.name(), "$(P)Voltage");
+    assert_eq!(file.records().len(), 2);
+}
+
+#[test]
+fn test_write_records() {
+    let path = std::env::temp_dir().join("epics_gen_test_write_records.db");
+    epics_gen::write_records(&path, [Voltage { val: 0.5 }]).unwrap();
+    epics_gen::append_records(&path, [Voltage { val: 1.5 }]).unwrap();
+    assert_eq!(
+        std::fs::read_to_string(&path).unwrap(),
+        r#"record(ao, "$(P)Voltage") {
+  field(VAL, "0.5")
+}
+record(ao, "$(P)Voltage") {
+  field(VAL, "1.5")
+}
+"#
+    );
+
+    // Writing overwrites the previous content
+    epics_gen::write_records(&path, [Voltage { val: 2.5 }]).unwrap();
+    assert_eq!(
+        std::fs::read_to_string(&path).unwrap(),
+        Voltage { val: 2.5 }.as_record()
+    );
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn test_validate_db_string() {
+    let mut file = RecordFile::new();
+    file.push(Voltage { val: 0.5 });
+    file.push(Channel {
+        current: 0.5,
+        slew_rate: 0.05,
+    });
+    assert_eq!(validate_db_string(&file.to_string()), Ok(()));
+
+    let db = r#"# power supply
+record(ai, "$(P)Current") {
+  field(INP, {const: 1.5})
+  field(SCAN, $(SCAN))
+  info(autosaveFields, "VAL")
+  alias("$(P)I")
+}
+alias("$(P)Current", "$(P)Curr")
+record(bo, "$(P)Enable")
+"#;
+    assert_eq!(validate_db_string(db), Ok(()));
+
+    let err = validate_db_string(
+        r#"record(ao, "$(P)Voltage") {
+  field(VAL, "0.5")
+"#,
+    )
+    .unwrap_err();
+    assert_eq!(err.line(), 2);
+    assert_eq!(
+        err.description(),
+        "expected `field`, `info`, `alias` or `}`, found end of input"
+    );
+
+    let err = validate_db_string(
+        r#"record(ao, "$(P)Voltage") {
+  field(VAL, "0.5"
+}
+"#,
+    )
+    .unwrap_err();
+    assert_eq!(err.line(), 3);
+    assert_eq!(err.description(), "expected `)`, found `}`");
+
+    let err = validate_db_string("record(ao, \"$(P)Voltage) {}\n").unwrap_err();
+    assert_eq!(err.line(), 1);
+    assert_eq!(err.description(), "unterminated string");
+}
+
+#[test]
+fn test_db_record_name_from_str() {
+    assert_eq!(
+        db_record_name_from_str(&Voltage { val: 0.5 }.as_record()),
+        Some("$(P)Voltage")
+    );
+    assert_eq!(
+        db_record_name_from_str("# record(ao, \"Comment\")\n\ngrecord( ai , \"$(P)Current\") {}"),
+        Some("$(P)Current")
+    );
+    assert_eq!(
+        db_record_name_from_str("record(bo, $(P)Enable) {}"),
+        Some("$(P)Enable")
+    );
+    assert_eq!(db_record_name_from_str("alias(\"$(P)A\", \"$(P)B\")"), None);
+    assert_eq!(db_record_name_from_str("record(ao, \"$(P)Voltage"), None);
+    assert_eq!(db_record_name_from_str(""), None);
+}
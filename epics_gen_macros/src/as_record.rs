@@ -1,28 +1,48 @@
 //! # as_record
 //!
-//! `as_record` is a macro that automatically implements an `as_record` member on a struct, to
+//! `as_record` is a macro that automatically implements the `AsRecord` trait on a struct, to
 //! enable serialization of structs into EPICS PVs.
 //!
 
-use quote::{quote, ToTokens};
-use syn::{parse::Parse, punctuated::Punctuated, Attribute, LitStr, Token, TypePath};
+use quote::{quote, quote_spanned, ToTokens};
+use syn::{
+    parse::Parse, parse_quote, punctuated::Punctuated, Attribute, LitFloat, LitInt, LitStr, Token,
+    TypePath, WherePredicate,
+};
 
 pub(super) fn impl_derive_as_record(
     ast: &syn::DeriveInput,
 ) -> syn::Result<proc_macro2::TokenStream> {
     let id = &ast.ident;
     // Destructure fields from the ast
-    let fields = if let syn::Data::Struct(syn::DataStruct {
-        fields: syn::Fields::Named(syn::FieldsNamed { ref named, .. }),
-        ..
-    }) = ast.data
-    {
-        named
-    } else {
-        return Err(syn::Error::new_spanned(
-            id,
-            "Annotated code is not a struct with punctuated fields.",
-        ));
+    let fields = match ast.data {
+        syn::Data::Struct(syn::DataStruct {
+            fields: syn::Fields::Named(syn::FieldsNamed { ref named, .. }),
+            ..
+        }) => named,
+        syn::Data::Struct(syn::DataStruct {
+            fields: syn::Fields::Unnamed(ref fields),
+            ..
+        }) => return impl_derive_as_record_newtype(ast, fields),
+        // Point at the `enum`/`union` keyword, which is what makes the item unsupported
+        syn::Data::Enum(syn::DataEnum { enum_token, .. }) => {
+            return Err(syn::Error::new_spanned(
+                enum_token,
+                "Annotated code is not a struct with punctuated fields.",
+            ))
+        }
+        syn::Data::Union(syn::DataUnion { union_token, .. }) => {
+            return Err(syn::Error::new_spanned(
+                union_token,
+                "Annotated code is not a struct with punctuated fields.",
+            ))
+        }
+        _ => {
+            return Err(syn::Error::new_spanned(
+                id,
+                "Annotated code is not a struct with punctuated fields.",
+            ))
+        }
     };
 
     let mut type_props = TypeProps::new(id.clone());
@@ -40,10 +60,192 @@ pub(super) fn impl_derive_as_record(
             }
             StructMeta::RecType { kw, val } => {
                 if let Some((fst_kw, _)) = type_props.type_rec_type {
-                    return Err(occurrence_error(fst_kw, kw, "rec_name"));
+                    return Err(occurrence_error(fst_kw, kw, "rec_type"));
                 }
                 type_props.type_rec_type = Some((kw, val));
             }
+            StructMeta::Desc { kw, val } => {
+                if let Some((fst_kw, _)) = type_props.desc {
+                    return Err(occurrence_error(fst_kw, kw, "desc"));
+                }
+                type_props.desc = Some((kw, val));
+            }
+            StructMeta::Egu { kw, val } => {
+                if let Some((fst_kw, _)) = type_props.egu {
+                    return Err(occurrence_error(fst_kw, kw, "egu"));
+                }
+                type_props.egu = Some((kw, val));
+            }
+            StructMeta::Lolo { kw, val } => {
+                if let Some((fst_kw, _)) = type_props.lolo {
+                    return Err(occurrence_error(fst_kw, kw, "lolo"));
+                }
+                type_props.lolo = Some((kw, val));
+            }
+            StructMeta::Low { kw, val } => {
+                if let Some((fst_kw, _)) = type_props.low {
+                    return Err(occurrence_error(fst_kw, kw, "low"));
+                }
+                type_props.low = Some((kw, val));
+            }
+            StructMeta::High { kw, val } => {
+                if let Some((fst_kw, _)) = type_props.high {
+                    return Err(occurrence_error(fst_kw, kw, "high"));
+                }
+                type_props.high = Some((kw, val));
+            }
+            StructMeta::Hihi { kw, val } => {
+                if let Some((fst_kw, _)) = type_props.hihi {
+                    return Err(occurrence_error(fst_kw, kw, "hihi"));
+                }
+                type_props.hihi = Some((kw, val));
+            }
+            StructMeta::MbbiStates { kw, val } => {
+                if let Some((fst_kw, _)) = type_props.mbbi_states {
+                    return Err(occurrence_error(fst_kw, kw, "mbbi_states"));
+                }
+                val.check_mbb_len()?;
+                type_props.mbbi_states = Some((kw, val));
+            }
+            StructMeta::MbbiValues { kw, val } => {
+                if let Some((fst_kw, _)) = type_props.mbbi_values {
+                    return Err(occurrence_error(fst_kw, kw, "mbbi_values"));
+                }
+                val.check_mbb_len()?;
+                type_props.mbbi_values = Some((kw, val));
+            }
+            StructMeta::CalcExpr { kw, val } => {
+                if let Some((fst_kw, _)) = type_props.calc_expr {
+                    return Err(occurrence_error(fst_kw, kw, "calc_expr"));
+                }
+                if let Err(msg) = validate_calc_expr(&val.value()) {
+                    return Err(syn::Error::new_spanned(val, msg));
+                }
+                type_props.calc_expr = Some((kw, val));
+            }
+            StructMeta::Dtyp { kw, val } => {
+                if let Some((fst_kw, _)) = type_props.dtyp {
+                    return Err(occurrence_error(fst_kw, kw, "dtyp"));
+                }
+                type_props.dtyp = Some((kw, val));
+            }
+            StructMeta::Scan { kw, val } => {
+                if let Some((fst_kw, _)) = type_props.scan {
+                    return Err(occurrence_error(fst_kw, kw, "scan"));
+                }
+                type_props.scan = Some((kw, val));
+            }
+            StructMeta::GlobalPrefix { kw, val } => {
+                if let Some((fst_kw, _)) = type_props.global_prefix {
+                    return Err(occurrence_error(fst_kw, kw, "global_prefix"));
+                }
+                type_props.global_prefix = Some((kw, val));
+            }
+            StructMeta::GlobalSuffix { kw, val } => {
+                if let Some((fst_kw, _)) = type_props.global_suffix {
+                    return Err(occurrence_error(fst_kw, kw, "global_suffix"));
+                }
+                type_props.global_suffix = Some((kw, val));
+            }
+            StructMeta::RecNameExpr { kw, val } => {
+                if let Some((fst_kw, _)) = type_props.rec_name_expr {
+                    return Err(occurrence_error(fst_kw, kw, "rec_name_expr"));
+                }
+                type_props.rec_name_expr = Some((kw, val));
+            }
+            StructMeta::AlsoRecName { kw, val } => {
+                type_props.also_rec_names.push((kw, val));
+            }
+            StructMeta::AlsoRecType { kw, val } => {
+                type_props.also_rec_types.push((kw, val));
+            }
+            StructMeta::SubstField { kw, val } => {
+                type_props.subst_fields.push((kw, val));
+            }
+            StructMeta::SubstPattern { kw, val } => {
+                type_props.subst_patterns.push((kw, val));
+            }
+            StructMeta::FmtSep { kw, val } => {
+                if let Some((fst_kw, _)) = type_props.fmt_sep {
+                    return Err(occurrence_error(fst_kw, kw, "fmt_sep"));
+                }
+                type_props.fmt_sep = Some((kw, val));
+            }
+            StructMeta::RecSyntax { kw, val } => {
+                if let Some((fst_kw, _)) = type_props.rec_syntax {
+                    return Err(occurrence_error(fst_kw, kw, "rec_syntax"));
+                }
+                if !["record", "grecord"].contains(&val.value().as_str()) {
+                    return Err(syn::Error::new_spanned(
+                        val,
+                        "rec_syntax must be \"record\" or \"grecord\"",
+                    ));
+                }
+                type_props.rec_syntax = Some((kw, val));
+            }
+            StructMeta::Bound { kw, val } => {
+                if let Some((fst_kw, _)) = type_props.bound {
+                    return Err(occurrence_error(fst_kw, kw, "bound"));
+                }
+                type_props.bound = Some((kw, val));
+            }
+            StructMeta::AsRecordFn { kw, val } => {
+                if let Some((fst_kw, _)) = type_props.as_record_fn {
+                    return Err(occurrence_error(fst_kw, kw, "as_record_fn"));
+                }
+                type_props.as_record_fn = Some((kw, val.parse()?));
+            }
+            StructMeta::PadFields { kw, val } => {
+                if let Some((fst_kw, _)) = type_props.pad_fields {
+                    return Err(occurrence_error(fst_kw, kw, "pad_fields"));
+                }
+                type_props.pad_fields = Some((kw, val));
+            }
+            StructMeta::Indent { kw, val } => {
+                if let Some((fst_kw, _)) = type_props.indent {
+                    return Err(occurrence_error(fst_kw, kw, "indent"));
+                }
+                type_props.indent = Some((kw, val));
+            }
+            StructMeta::Comment { kw, val } => {
+                type_props.comments.push((kw, val));
+            }
+            StructMeta::Strict { kw } => {
+                if type_props.strict.is_some() {
+                    return Err(syn::Error::new_spanned(
+                        kw,
+                        "Found multiple occurrences of record(strict)",
+                    ));
+                }
+                type_props.strict = Some(kw);
+            }
+            StructMeta::ImplDisplay { kw } => {
+                if type_props.impl_display.is_some() {
+                    return Err(syn::Error::new_spanned(
+                        kw,
+                        "Found multiple occurrences of record(impl_display)",
+                    ));
+                }
+                type_props.impl_display = Some(kw);
+            }
+            StructMeta::StrictTypeCheck { kw } => {
+                if type_props.strict_type_check.is_some() {
+                    return Err(syn::Error::new_spanned(
+                        kw,
+                        "Found multiple occurrences of record(strict_type_check)",
+                    ));
+                }
+                type_props.strict_type_check = Some(kw);
+            }
+            StructMeta::Pini { kw } => {
+                if type_props.pini.is_some() {
+                    return Err(syn::Error::new_spanned(
+                        kw,
+                        "Found multiple occurrences of record(pini)",
+                    ));
+                }
+                type_props.pini = Some(kw);
+            }
         }
     }
 
@@ -61,7 +263,6 @@ pub(super) fn impl_derive_as_record(
                     if let Some((fst_kw, _)) = field_props.rec_name {
                         return Err(occurrence_error(fst_kw, kw, "rec_name"));
                     }
-                    // TODO: This check is also done in the generate method, we can probably delete this
                     if let Some((fst_kw, _)) = type_props.type_rec_name {
                         return Err(rec_def_error(fst_kw, kw, "rec_name"));
                     }
@@ -71,49 +272,530 @@ pub(super) fn impl_derive_as_record(
                     if let Some((fst_kw, _)) = field_props.rec_type {
                         return Err(occurrence_error(fst_kw, kw, "rec_type"));
                     }
-                    // TODO: This check is also done in the generate method, we can probably delete this
                     if let Some((fst_kw, _)) = type_props.type_rec_type {
                         return Err(rec_def_error(fst_kw, kw, "rec_type"));
                     }
                     field_props.rec_type = Some((kw, val));
                 }
                 FieldMeta::RecField { kw, val } => {
-                    if let Some((fst_kw, _)) = field_props.field_name {
-                        return Err(occurrence_error(fst_kw, kw, "rec_field"));
+                    match &field_props.field_name {
+                        Some((fst_kw, _)) if field_props.link => {
+                            return Err(conflict_error(fst_kw, kw, "field", "link_field"))
+                        }
+                        Some((fst_kw, _)) => {
+                            return Err(occurrence_error(fst_kw, &kw.to_token_stream(), "field"))
+                        }
+                        None => {}
                     }
-                    field_props.field_name = Some((kw, val))
+                    field_props.field_name = Some((kw.to_token_stream(), val))
                 }
-                FieldMeta::Subst { kw, val } => {
-                    if let Some((fst_kw, _)) = field_props.subst {
-                        return Err(occurrence_error(fst_kw, kw, "subst"));
+                FieldMeta::LinkField { kw, val } => {
+                    match &field_props.field_name {
+                        Some((fst_kw, _)) if field_props.link => {
+                            return Err(occurrence_error(
+                                fst_kw,
+                                &kw.to_token_stream(),
+                                "link_field",
+                            ))
+                        }
+                        Some((fst_kw, _)) => {
+                            return Err(conflict_error(fst_kw, kw, "link_field", "field"))
+                        }
+                        None => {}
                     }
-                    field_props.subst = Some((kw, val));
+                    field_props.field_name = Some((kw.to_token_stream(), val));
+                    field_props.link = true;
+                }
+                FieldMeta::LinkTarget { kw, val } => {
+                    if let Some((fst_kw, _)) = field_props.link_target {
+                        return Err(occurrence_error(fst_kw, kw, "link_target"));
+                    }
+                    field_props.link_target = Some((kw, val));
+                }
+                FieldMeta::LinkModifier { kw, val } => {
+                    if let Some((fst_kw, _)) = field_props.link_modifier {
+                        return Err(occurrence_error(fst_kw, kw, "link_modifier"));
+                    }
+                    if let Some(modifier) = val
+                        .value()
+                        .split_whitespace()
+                        .find(|modifier| !LINK_MODIFIERS.contains(modifier))
+                    {
+                        return Err(syn::Error::new_spanned(
+                            val,
+                            format!(
+                                "unknown link modifier `{}`, expected one of {}",
+                                modifier,
+                                LINK_MODIFIERS.join(", ")
+                            ),
+                        ));
+                    }
+                    field_props.link_modifier = Some((kw, val));
+                }
+                FieldMeta::Subst { kw, val } => {
+                    field_props.subst.push((kw, val));
                 }
                 FieldMeta::Repr { kw, val } => {
-                    if let Some((fst_kw, _)) = field_props.repr {
-                        return Err(occurrence_error(fst_kw, kw, "repr"));
+                    match &field_props.repr {
+                        Some((fst_kw, ReprKind::Expr(_))) => {
+                            return Err(conflict_error(fst_kw, kw, "repr", "repr_expr"))
+                        }
+                        Some((fst_kw, _)) => {
+                            return Err(occurrence_error(fst_kw, &kw.to_token_stream(), "repr"))
+                        }
+                        None => {}
+                    }
+                    field_props.repr = Some((kw.to_token_stream(), val));
+                }
+                FieldMeta::ReprExpr { kw, val } => {
+                    match &field_props.repr {
+                        Some((fst_kw, ReprKind::Expr(_))) => {
+                            return Err(occurrence_error(
+                                fst_kw,
+                                &kw.to_token_stream(),
+                                "repr_expr",
+                            ))
+                        }
+                        Some((fst_kw, _)) => {
+                            return Err(conflict_error(fst_kw, kw, "repr_expr", "repr"))
+                        }
+                        None => {}
                     }
-                    field_props.repr = Some((kw, val));
+                    field_props.repr = Some((kw.to_token_stream(), ReprKind::Expr(val.parse()?)));
                 }
                 FieldMeta::Fmt { kw, val } => {
                     if let Some((fst_kw, _)) = field_props.format {
-                        return Err(occurrence_error(fst_kw, kw, "repr"));
+                        return Err(occurrence_error(fst_kw, kw, "fmt"));
                     }
                     field_props.format = Some((kw, val));
                 }
+                FieldMeta::Precision { kw, val } => {
+                    if let Some((fst_kw, _)) = field_props.precision {
+                        return Err(occurrence_error(fst_kw, kw, "precision"));
+                    }
+                    field_props.precision = Some((kw, val));
+                }
+                FieldMeta::Hex { kw } => {
+                    if field_props.hex {
+                        return Err(syn::Error::new_spanned(
+                            kw,
+                            "Found multiple occurrences of record(hex)",
+                        ));
+                    }
+                    if field_props.bin {
+                        return Err(syn::Error::new_spanned(
+                            kw,
+                            "`hex` cannot be used together with `bin`",
+                        ));
+                    }
+                    field_props.hex = true;
+                }
+                FieldMeta::Bin { kw } => {
+                    if field_props.bin {
+                        return Err(syn::Error::new_spanned(
+                            kw,
+                            "Found multiple occurrences of record(bin)",
+                        ));
+                    }
+                    if field_props.hex {
+                        return Err(syn::Error::new_spanned(
+                            kw,
+                            "`bin` cannot be used together with `hex`",
+                        ));
+                    }
+                    field_props.bin = true;
+                }
+                FieldMeta::Width { kw, val } => {
+                    if let Some((fst_kw, _)) = field_props.width {
+                        return Err(occurrence_error(fst_kw, kw, "width"));
+                    }
+                    field_props.width = Some((kw, val));
+                }
+                FieldMeta::Align { kw, val } => {
+                    if let Some((fst_kw, _)) = field_props.align {
+                        return Err(occurrence_error(fst_kw, kw, "align"));
+                    }
+                    if !matches!(val.value().as_str(), "left" | "right" | "center") {
+                        return Err(syn::Error::new_spanned(
+                            val,
+                            "expected \"left\", \"right\" or \"center\"",
+                        ));
+                    }
+                    field_props.align = Some((kw, val));
+                }
+                FieldMeta::Comment { kw, val } => {
+                    field_props.comments.push((kw, val));
+                }
+                FieldMeta::NoQuote { kw } => {
+                    if field_props.no_quote {
+                        return Err(syn::Error::new_spanned(
+                            kw,
+                            "Found multiple occurrences of record(no_quote)",
+                        ));
+                    }
+                    field_props.no_quote = true;
+                }
+                FieldMeta::FieldIf { kw, val } => {
+                    if let Some((fst_kw, _)) = field_props.field_if {
+                        return Err(occurrence_error(fst_kw, kw, "field_if"));
+                    }
+                    field_props.field_if = Some((kw, val));
+                }
+                FieldMeta::FmtFile { kw, val } => {
+                    if let Some((fst_kw, _)) = field_props.fmt_file {
+                        return Err(occurrence_error(fst_kw, kw, "fmt_file"));
+                    }
+                    field_props.fmt_file = Some((kw, val));
+                }
+                FieldMeta::Default { kw, val } => {
+                    if let Some((fst_kw, _)) = field_props.default_val {
+                        return Err(occurrence_error(fst_kw, kw, "default"));
+                    }
+                    if !type_is_option(&field.ty) {
+                        return Err(syn::Error::new_spanned(
+                            kw,
+                            "`default` can only be used on an `Option` member",
+                        ));
+                    }
+                    field_props.default_val = Some((kw, val));
+                }
+                FieldMeta::Skip { kw } => {
+                    if field_props.skip {
+                        return Err(syn::Error::new_spanned(
+                            kw,
+                            "Found multiple occurrences of record(skip)",
+                        ));
+                    }
+                    field_props.skip = true;
+                }
+            }
+        }
+        if let (Some((kw, _)), None) = (&field_props.align, &field_props.width) {
+            return Err(syn::Error::new_spanned(kw, "`align` requires `width`"));
+        }
+        // The file contents are used the same as the `fmt` attribute
+        if let Some((kw, path)) = &field_props.fmt_file {
+            if let Some((fmt_kw, _)) = &field_props.format {
+                return Err(conflict_error(
+                    &fmt_kw.to_token_stream(),
+                    kw,
+                    "fmt_file",
+                    "fmt",
+                ));
+            }
+            field_props.format = Some((kw::fmt(kw.span), read_fmt_file(path)?));
+        }
+        if !field_props.link {
+            if let Some((kw, _)) = &field_props.link_target {
+                return Err(syn::Error::new_spanned(kw, "`link_target` requires `link_field`"));
+            }
+            if let Some((kw, _)) = &field_props.link_modifier {
+                return Err(syn::Error::new_spanned(kw, "`link_modifier` requires `link_field`"));
             }
         }
         type_props.fields.push(field_props);
     }
 
+    if let Some((_, val)) = &type_props.desc {
+        if val.value().len() > DESC_MAX_LEN {
+            return Err(syn::Error::new_spanned(
+                val,
+                format!("DESC field exceeds {} characters", DESC_MAX_LEN),
+            ));
+        }
+    }
+
+    if let Some((_, val)) = &type_props.egu {
+        if val.value().len() > EGU_MAX_LEN {
+            return Err(syn::Error::new_spanned(
+                val,
+                format!("EGU field exceeds {} characters", EGU_MAX_LEN),
+            ));
+        }
+    }
+
+    if let (Some((_, states)), Some((_, values))) = (&type_props.mbbi_states, &type_props.mbbi_values)
+    {
+        if states.elems.len() != values.elems.len() {
+            let mut err = syn::Error::new(
+                values.bracket.span.join(),
+                format!(
+                    "mbbi_values has {} entries, but mbbi_states has {}",
+                    values.elems.len(),
+                    states.elems.len()
+                ),
+            );
+            err.combine(syn::Error::new(
+                states.bracket.span.join(),
+                "mbbi_states defined here",
+            ));
+            return Err(err);
+        }
+    }
+
+    type_props.check_shortcut_fields()?;
+    type_props.check_strict()?;
     let func = type_props.generate()?;
+    let fields_func = type_props.generate_as_record_fields()?;
+
+    // Every type parameter is printed by default, `bound` replaces the inferred bounds
+    let mut generics = ast.generics.clone();
+    let where_clause = generics.make_where_clause();
+    match &type_props.bound {
+        Some((_, val)) => where_clause
+            .predicates
+            .extend(val.parse_with(Punctuated::<WherePredicate, Token![,]>::parse_terminated)?),
+        None => {
+            for param in ast.generics.type_params() {
+                let ident = &param.ident;
+                where_clause
+                    .predicates
+                    .push(parse_quote!(#ident: std::fmt::Display + Clone));
+            }
+        }
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let mut warnings = type_props.alarm_limit_warnings();
+    warnings.extend(type_props.type_check_warnings());
+    // `include_str!` makes cargo rebuild the crate when a `fmt_file` changes
+    let fmt_files = type_props.fields.iter().filter_map(|field| {
+        let (_, path) = field.fmt_file.as_ref()?;
+        let path = fmt_file_path(path).to_string_lossy().into_owned();
+        Some(quote! { const _: &str = include_str!(#path); })
+    });
+    // The trait method keeps its name, `as_record_fn` adds an inherent method with the given name
+    let as_record_fn = type_props.as_record_fn.as_ref().map(|(_, name)| {
+        let vis = &ast.vis;
+        quote! {
+            impl #impl_generics #id #ty_generics #where_clause {
+                /// Returns the record definition, the same as `AsRecord::as_record`.
+                #vis fn #name(&self) -> String {
+                    epics_gen::AsRecord::as_record(self)
+                }
+            }
+        }
+    });
+    let impl_display = type_props.impl_display.as_ref().map(|_| {
+        quote! {
+            impl #impl_generics std::fmt::Display for #id #ty_generics #where_clause {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    f.write_str(&epics_gen::AsRecord::as_record(self))
+                }
+            }
+        }
+    });
     Ok(quote!(
-        impl #id {
+        #(#warnings)*
+        #(#fmt_files)*
+
+        impl #impl_generics epics_gen::AsRecord for #id #ty_generics #where_clause {
             #func
+
+            #fields_func
         }
+
+        #as_record_fn
+        #impl_display
     ))
 }
 
+/// Implements `AsRecord` for a newtype wrapper (`struct Wrapper(Inner)`) by delegating to the
+/// wrapped value, which has to implement `AsRecord` as well.
+fn impl_derive_as_record_newtype(
+    ast: &syn::DeriveInput,
+    fields: &syn::FieldsUnnamed,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let id = &ast.ident;
+    if fields.unnamed.len() != 1 {
+        return Err(syn::Error::new_spanned(
+            fields,
+            format!(
+                "Tuple struct `{}` has {} fields, only tuple structs with a single field (newtype \
+                 wrappers) are supported.",
+                id,
+                fields.unnamed.len()
+            ),
+        ));
+    }
+    if let Some(attr) = ast
+        .attrs
+        .iter()
+        .chain(fields.unnamed.iter().flat_map(|field| &field.attrs))
+        .find(|attr| attr.path().is_ident("record"))
+    {
+        return Err(syn::Error::new_spanned(
+            attr,
+            "record attributes are not supported on newtype wrappers, annotate the wrapped type instead",
+        ));
+    }
+
+    let inner = &fields.unnamed[0].ty;
+    let mut generics = ast.generics.clone();
+    generics
+        .make_where_clause()
+        .predicates
+        .push(parse_quote!(#inner: epics_gen::AsRecord));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    Ok(quote!(
+        impl #impl_generics epics_gen::AsRecord for #id #ty_generics #where_clause {
+            fn as_record(&self) -> String {
+                epics_gen::AsRecord::as_record(&self.0)
+            }
+
+            fn as_records(&self) -> Vec<String> {
+                epics_gen::AsRecord::as_records(&self.0)
+            }
+
+            fn as_record_write<W: std::io::Write>(&self, w: &mut W) -> std::io::Result<()> {
+                epics_gen::AsRecord::as_record_write(&self.0, w)
+            }
+
+            fn as_record_fields(&self) -> Vec<(String, String)> {
+                epics_gen::AsRecord::as_record_fields(&self.0)
+            }
+        }
+    ))
+}
+
+/// Comment lines (`# <comment>`) as a part of a format string, every line is indented by `indent`.
+fn comment_lines(comments: &[(kw::comment, LitStr)], indent: &str) -> String {
+    comments
+        .iter()
+        .flat_map(|(_, comment)| {
+            comment
+                .value()
+                .lines()
+                .map(|line| format!("{}# {}\n", indent, escape_braces(line)))
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+/// Record types that have the alarm limit fields (`LOLO`, `LOW`, `HIGH`, `HIHI`)
+const ALARM_LIMIT_REC_TYPES: [&str; 8] = [
+    "ai", "ao", "longin", "longout", "int64in", "int64out", "calc", "calcout",
+];
+
+/// Record types checked by `strict_type_check`, which need a `CALC` field
+const CALC_REC_TYPES: [&str; 2] = ["calc", "calcout"];
+
+/// Emits a compiler warning at `span`. Proc macros can't emit warnings on stable, so a use of a
+/// deprecated item is generated instead. The item is named `name`, which is a part of the warning
+/// ("use of deprecated unit struct `<name>`: <msg>").
+fn compile_warning(span: proc_macro2::Span, name: &str, msg: &str) -> proc_macro2::TokenStream {
+    let name = syn::Ident::new(name, span);
+    quote_spanned! {span=>
+        const _: () = {
+            #[deprecated(note = #msg)]
+            struct #name;
+            let _ = #name;
+        };
+    }
+}
+
+/// Maximum length of the EPICS DESC field
+const DESC_MAX_LEN: usize = 40;
+
+/// Maximum length of the EPICS EGU field
+const EGU_MAX_LEN: usize = 16;
+
+/// Field name prefixes of the mbbi/mbbo states, `ZRST`/`ZRVL` for state 0, `ONST`/`ONVL` for
+/// state 1, …
+const MBB_STATE_PREFIXES: [&str; 16] = [
+    "ZR", "ON", "TW", "TH", "FR", "FV", "SX", "SV", "EI", "NI", "TE", "EL", "TV", "TT", "FT", "FF",
+];
+
+/// Process passive and maximize severity modifiers of an EPICS link
+const LINK_MODIFIERS: [&str; 9] = ["PP", "NPP", "CA", "CP", "CPP", "MS", "NMS", "MSS", "MSI"];
+
+/// Maximum length of the `calc_expr` expression
+const CALC_MAX_LEN: usize = 40;
+
+/// Maximum length of an EPICS record name
+const REC_NAME_MAX_LEN: usize = 60;
+
+/// Checks the length of the record name `name` printed for the `rec_name` (or `also_rec_name`)
+/// literal `val`, including the global prefix and suffix. Names containing macros (`$(P)`) are not
+/// checked, because the length of their expansion is unknown.
+fn check_rec_name_len(val: &syn::LitStr, name: &str) -> syn::Result<()> {
+    if !name.contains('$') && name.len() > REC_NAME_MAX_LEN {
+        let msg = if name == val.value() {
+            format!("record name exceeds {} characters", REC_NAME_MAX_LEN)
+        } else {
+            format!(
+                "record name `{}` (with the global prefix and suffix) exceeds {} characters",
+                name, REC_NAME_MAX_LEN
+            )
+        };
+        return Err(syn::Error::new_spanned(val, msg));
+    }
+    Ok(())
+}
+
+/// Checks the expression of a calc record. Only the input variables `A`-`L` (`INPA`-`INPL`) can be
+/// used as single letter operands, multi letter words are treated as functions or constants.
+fn validate_calc_expr(expr: &str) -> Result<(), &'static str> {
+    if expr.trim().is_empty() {
+        return Err("calc expression is empty");
+    }
+    if expr.len() > CALC_MAX_LEN {
+        return Err("calc expression exceeds 40 characters");
+    }
+    if let Some(c) = expr
+        .chars()
+        .find(|c| !c.is_ascii_alphanumeric() && !" .,+-*/%^<>=!#&|~?:;()".contains(*c))
+    {
+        return Err(if c.is_ascii() {
+            "calc expression contains an illegal character"
+        } else {
+            "calc expression contains a non-ASCII character"
+        });
+    }
+
+    let mut depth = 0usize;
+    for c in expr.chars() {
+        match c {
+            '(' => depth += 1,
+            ')' => {
+                depth = depth
+                    .checked_sub(1)
+                    .ok_or("unbalanced parentheses in calc expression")?
+            }
+            _ => (),
+        }
+    }
+    if depth != 0 {
+        return Err("unbalanced parentheses in calc expression");
+    }
+
+    if calc_expr_words(expr).any(|c| !('A'..='L').contains(&c.to_ascii_uppercase())) {
+        return Err("calc expression can only use the input variables A-L");
+    }
+    Ok(())
+}
+
+/// Single letter operands of a calc expression.
+fn calc_expr_words(expr: &str) -> impl Iterator<Item = char> + '_ {
+    let is_word_char = |c: char| c.is_ascii_alphanumeric() || c == '_';
+    expr.split(move |c: char| !is_word_char(c)).filter_map(|word| {
+        let mut chars = word.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) if c.is_ascii_alphabetic() => Some(c),
+            _ => None,
+        }
+    })
+}
+
+/// Input variables (`A`-`L`, uppercase) used by a calc expression, sorted and deduplicated.
+fn calc_expr_inputs(expr: &str) -> Vec<char> {
+    let mut res: Vec<char> = calc_expr_words(expr)
+        .map(|c| c.to_ascii_uppercase())
+        .collect();
+    res.sort_unstable();
+    res.dedup();
+    res
+}
+
 mod kw {
     use syn::custom_keyword;
 
@@ -122,7 +804,50 @@ mod kw {
     custom_keyword!(field);
     custom_keyword!(subst);
     custom_keyword!(repr);
+    custom_keyword!(repr_expr);
     custom_keyword!(fmt);
+    custom_keyword!(skip);
+    custom_keyword!(precision);
+    custom_keyword!(hex);
+    custom_keyword!(bin);
+    custom_keyword!(pini);
+    custom_keyword!(scan);
+    custom_keyword!(dtyp);
+    custom_keyword!(desc);
+    custom_keyword!(field_if);
+    custom_keyword!(global_prefix);
+    custom_keyword!(global_suffix);
+    custom_keyword!(rec_name_expr);
+    custom_keyword!(egu);
+    custom_keyword!(also_rec_name);
+    custom_keyword!(also_rec_type);
+    custom_keyword!(subst_field);
+    custom_keyword!(subst_pattern);
+    custom_keyword!(fmt_sep);
+    custom_keyword!(strict);
+    custom_keyword!(strict_type_check);
+    custom_keyword!(width);
+    custom_keyword!(align);
+    custom_keyword!(calc_expr);
+    custom_keyword!(rec_syntax);
+    custom_keyword!(bound);
+    custom_keyword!(as_record_fn);
+    custom_keyword!(impl_display);
+    custom_keyword!(no_quote);
+    custom_keyword!(comment);
+    custom_keyword!(lolo);
+    custom_keyword!(low);
+    custom_keyword!(high);
+    custom_keyword!(hihi);
+    custom_keyword!(mbbi_states);
+    custom_keyword!(mbbi_values);
+    custom_keyword!(link_field);
+    custom_keyword!(link_target);
+    custom_keyword!(link_modifier);
+    custom_keyword!(indent);
+    custom_keyword!(pad_fields);
+    custom_keyword!(default);
+    custom_keyword!(fmt_file);
 }
 
 /// Attributes that appear through the whole type
@@ -134,6 +859,71 @@ struct TypeProps {
     pub type_rec_name: Option<(kw::rec_name, LitStr)>,
     /// `rec_type` attribute appearing on the top of the type(struct)
     pub type_rec_type: Option<(kw::rec_type, LitStr)>,
+    /// `rec_name_expr` attribute, record name computed at runtime instead of `rec_name`
+    pub rec_name_expr: Option<(kw::rec_name_expr, LitStr)>,
+    /// `desc` attribute, adds `field(DESC, "<val>")` to every record
+    pub desc: Option<(kw::desc, LitStr)>,
+    /// `egu` attribute, adds `field(EGU, "<val>")` to every record
+    pub egu: Option<(kw::egu, LitStr)>,
+    /// `calc_expr` attribute, adds `field(CALC, "<val>")` to every record
+    pub calc_expr: Option<(kw::calc_expr, LitStr)>,
+    /// `lolo` attribute, adds `field(LOLO, "<val>")` to every record
+    pub lolo: Option<(kw::lolo, AlarmLimit)>,
+    /// `low` attribute, adds `field(LOW, "<val>")` to every record
+    pub low: Option<(kw::low, AlarmLimit)>,
+    /// `high` attribute, adds `field(HIGH, "<val>")` to every record
+    pub high: Option<(kw::high, AlarmLimit)>,
+    /// `hihi` attribute, adds `field(HIHI, "<val>")` to every record
+    pub hihi: Option<(kw::hihi, AlarmLimit)>,
+    /// `mbbi_states` attribute, adds `field(ZRST, "<val>")`, `field(ONST, "<val>")`, … to every
+    /// record
+    pub mbbi_states: Option<(kw::mbbi_states, LitArray<LitStr>)>,
+    /// `mbbi_values` attribute, adds `field(ZRVL, "<val>")`, `field(ONVL, "<val>")`, … to every
+    /// record
+    pub mbbi_values: Option<(kw::mbbi_values, LitArray<LitInt>)>,
+    /// `dtyp` attribute, adds `field(DTYP, "<val>")` to every record
+    pub dtyp: Option<(kw::dtyp, LitStr)>,
+    /// `scan` attribute, adds `field(SCAN, "<val>")` to every record
+    pub scan: Option<(kw::scan, LitStr)>,
+    /// `pini` attribute, adds `field(PINI, "YES")` to every record
+    pub pini: Option<kw::pini>,
+    /// `global_prefix` attribute, prepended to every `rec_name`
+    pub global_prefix: Option<(kw::global_prefix, LitStr)>,
+    /// `global_suffix` attribute, appended to every `rec_name`
+    pub global_suffix: Option<(kw::global_suffix, LitStr)>,
+    /// `also_rec_name` attributes, names of the companion records printed after the global record
+    pub also_rec_names: Vec<(kw::also_rec_name, LitStr)>,
+    /// `also_rec_type` attributes, types of the companion records (paired with `also_rec_name` in
+    /// order)
+    pub also_rec_types: Vec<(kw::also_rec_type, LitStr)>,
+    /// `subst_field` attributes, members whose values replace the `subst_pattern` patterns
+    pub subst_fields: Vec<(kw::subst_field, LitStr)>,
+    /// `subst_pattern` attributes, patterns substituted by the `subst_field` members (paired in
+    /// order)
+    pub subst_patterns: Vec<(kw::subst_pattern, LitStr)>,
+    /// `fmt_sep` attribute, separator between the record blocks in multi-record mode (`"\n"` by
+    /// default)
+    pub fmt_sep: Option<(kw::fmt_sep, LitStr)>,
+    /// `strict` attribute, every member has to be annotated (or skipped)
+    pub strict: Option<kw::strict>,
+    /// `strict_type_check` attribute, warns about fields missing for the record type (e.g. `CALC`
+    /// of calc records)
+    pub strict_type_check: Option<kw::strict_type_check>,
+    /// `rec_syntax` attribute, keyword of the record definitions (`record` by default)
+    pub rec_syntax: Option<(kw::rec_syntax, LitStr)>,
+    /// `bound` attribute, where clause predicates of the impl replacing the inferred
+    /// `T: Display + Clone` bounds of the type parameters
+    pub bound: Option<(kw::bound, LitStr)>,
+    /// `as_record_fn` attribute, name of an inherent method delegating to `AsRecord::as_record`
+    pub as_record_fn: Option<(kw::as_record_fn, syn::Ident)>,
+    /// `impl_display` attribute, implements `Display` printing the same string as `as_record`
+    pub impl_display: Option<kw::impl_display>,
+    /// `comment` attributes, `# <comment>` lines printed before every record block
+    pub comments: Vec<(kw::comment, LitStr)>,
+    /// `indent` attribute, indentation of the lines inside a record (two spaces by default)
+    pub indent: Option<(kw::indent, IndentKind)>,
+    /// `pad_fields` attribute, aligns the values of the `field(…)` lines
+    pub pad_fields: Option<(kw::pad_fields, syn::LitBool)>,
     pub fields: Vec<FieldProps>,
 }
 
@@ -143,125 +933,703 @@ impl TypeProps {
             ident,
             type_rec_name: Default::default(),
             type_rec_type: Default::default(),
+            rec_name_expr: Default::default(),
+            desc: Default::default(),
+            egu: Default::default(),
+            calc_expr: Default::default(),
+            lolo: Default::default(),
+            low: Default::default(),
+            high: Default::default(),
+            hihi: Default::default(),
+            mbbi_states: Default::default(),
+            mbbi_values: Default::default(),
+            dtyp: Default::default(),
+            scan: Default::default(),
+            pini: Default::default(),
+            global_prefix: Default::default(),
+            global_suffix: Default::default(),
+            also_rec_names: Default::default(),
+            also_rec_types: Default::default(),
+            subst_fields: Default::default(),
+            subst_patterns: Default::default(),
+            fmt_sep: Default::default(),
+            strict: Default::default(),
+            strict_type_check: Default::default(),
+            rec_syntax: Default::default(),
+            bound: Default::default(),
+            as_record_fn: Default::default(),
+            impl_display: Default::default(),
+            comments: Default::default(),
+            indent: Default::default(),
+            pad_fields: Default::default(),
             fields: Default::default(),
         }
     }
 
+    /// Fields defined by struct level attributes, which are inserted at the top of every record
+    /// generated by the struct. The result is used as a part of a format string.
+    fn common_fields(&self) -> String {
+        self.common_field_values()
+            .iter()
+            .map(|(name, val)| self.field_line(name, &format!("\"{}\"", escape_braces(val))))
+            .collect()
+    }
+
+    /// `field(<name>, <value>)` line of a record. With `pad_fields` the values of all the lines are
+    /// aligned to the longest field name of the struct.
+    fn field_line(&self, name: &str, value: &str) -> String {
+        let pad = self.field_name_width().saturating_sub(name.chars().count());
+        format!(
+            "{}field({},{} {})\n",
+            self.indent(),
+            name,
+            " ".repeat(pad),
+            value
+        )
+    }
+
+    /// Length of the longest field name printed by the struct, 0 without `pad_fields`.
+    fn field_name_width(&self) -> usize {
+        if !self.pad_fields.as_ref().is_some_and(|(_, val)| val.value) {
+            return 0;
+        }
+        let common = self.common_field_values().into_iter().map(|(name, _)| name);
+        let members = self
+            .fields
+            .iter()
+            .filter(|field| !field.skip && field.subst.is_empty() && field.format.is_none())
+            .filter_map(|field| field.field_name.as_ref().map(|(_, val)| val.value()));
+        common
+            .chain(members)
+            .map(|name| name.chars().count())
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Indentation of the lines inside a record.
+    fn indent(&self) -> String {
+        match &self.indent {
+            Some((_, indent)) => indent.prefix(),
+            None => IndentKind::default().prefix(),
+        }
+    }
+
+    /// Fields added to every record by the struct attributes (`desc`, `egu`, …), as name/value
+    /// pairs.
+    fn common_field_values(&self) -> Vec<(String, String)> {
+        let mut res = Vec::new();
+        if let Some((_, val)) = &self.desc {
+            res.push(("DESC".to_string(), val.value()));
+        }
+        if let Some((_, val)) = &self.egu {
+            res.push(("EGU".to_string(), val.value()));
+        }
+        if let Some((_, val)) = &self.calc_expr {
+            res.push(("CALC".to_string(), val.value()));
+        }
+        if let Some((_, val)) = &self.dtyp {
+            res.push(("DTYP".to_string(), val.value()));
+        }
+        if let Some((_, val)) = &self.scan {
+            res.push(("SCAN".to_string(), val.value()));
+        }
+        if self.pini.is_some() {
+            res.push(("PINI".to_string(), "YES".to_string()));
+        }
+        for (name, _, val) in self.alarm_limits() {
+            res.push((name.to_string(), val.0.clone()));
+        }
+        for (name, _, val) in self.mbb_states() {
+            res.push((name, val));
+        }
+        res
+    }
+
+    /// Generates `as_record_fields`, which returns the name and the value of every field printed by
+    /// `as_record`, in the same order. Members printed with `fmt` are not a part of the output.
+    fn generate_as_record_fields(&self) -> syn::Result<proc_macro2::TokenStream> {
+        let single_record = self.type_rec_type.is_some();
+        let common = self.common_field_values().into_iter().map(|(name, val)| {
+            quote! { fields.push((#name.to_string(), #val.to_string())); }
+        });
+        let common = quote! { #(#common)* };
+
+        let mut pushes = Vec::new();
+        for field in &self.fields {
+            let Some((_, name)) = &field.field_name else {
+                continue;
+            };
+            if field.skip || !field.subst.is_empty() || field.format.is_some() {
+                continue;
+            }
+            let spec = field.value_spec();
+            let value = field.value_arg(!single_record);
+            let mut push = quote! { fields.push((#name.to_string(), format!(#spec, #value))); };
+            // Every member prints its own record, together with the common fields
+            if !single_record {
+                push = quote! { #common #push };
+            }
+            pushes.push(match field.condition()? {
+                Some(cond) => quote! { if #cond { #push } },
+                None => push,
+            });
+        }
+        let common = if single_record { common } else { quote! {} };
+        let substs = self.struct_substs()?.into_iter().chain(self.fields.iter().flat_map(|field| {
+            let ident = &field.ident;
+            field.subst.iter().map(move |(_, val)| {
+                let value = val.value();
+                quote! { let res = res.replace(&#value, &self.#ident.to_string()); }
+            })
+        }));
+
+        Ok(quote! {
+            fn as_record_fields(&self) -> Vec<(String, String)> {
+                let mut fields: Vec<(String, String)> = Vec::new();
+                #common
+                #(#pushes)*
+                fields
+                    .into_iter()
+                    .map(|(name, res)| {
+                        #(#substs)*
+                        (name, res)
+                    })
+                    .collect()
+            }
+        })
+    }
+
+    /// Fields of the mbbi/mbbo states defined by `mbbi_states` and `mbbi_values`, with the
+    /// attribute keyword. The string and the value of each state are printed together.
+    fn mbb_states(&self) -> Vec<(String, proc_macro2::TokenStream, String)> {
+        let mut res = Vec::new();
+        for (i, prefix) in MBB_STATE_PREFIXES.iter().enumerate() {
+            if let Some((kw, states)) = &self.mbbi_states {
+                if let Some(state) = states.elems.iter().nth(i) {
+                    res.push((format!("{}ST", prefix), kw.to_token_stream(), state.value()));
+                }
+            }
+            if let Some((kw, values)) = &self.mbbi_values {
+                if let Some(value) = values.elems.iter().nth(i) {
+                    res.push((
+                        format!("{}VL", prefix),
+                        kw.to_token_stream(),
+                        value.base10_digits().to_string(),
+                    ));
+                }
+            }
+        }
+        res
+    }
+
+    /// Alarm limits defined by struct level attributes, with the field name and the attribute
+    /// keyword.
+    fn alarm_limits(&self) -> Vec<(&'static str, proc_macro2::TokenStream, &AlarmLimit)> {
+        let mut res = Vec::new();
+        if let Some((kw, val)) = &self.lolo {
+            res.push(("LOLO", kw.to_token_stream(), val));
+        }
+        if let Some((kw, val)) = &self.low {
+            res.push(("LOW", kw.to_token_stream(), val));
+        }
+        if let Some((kw, val)) = &self.high {
+            res.push(("HIGH", kw.to_token_stream(), val));
+        }
+        if let Some((kw, val)) = &self.hihi {
+            res.push(("HIHI", kw.to_token_stream(), val));
+        }
+        res
+    }
+
+    /// Warns about alarm limits used with a record type that doesn't have the alarm limit fields.
+    /// It is not an error, since the record type can't be checked reliably (e.g. custom record
+    /// types).
+    fn alarm_limit_warnings(&self) -> Vec<proc_macro2::TokenStream> {
+        let rec_types: Vec<&LitStr> = match &self.type_rec_type {
+            Some((_, rec_type)) => vec![rec_type],
+            None => self
+                .fields
+                .iter()
+                .filter_map(|field| field.rec_type.as_ref().map(|(_, rec_type)| rec_type))
+                .collect(),
+        };
+        let Some(rec_type) = rec_types
+            .into_iter()
+            .find(|rec_type| !ALARM_LIMIT_REC_TYPES.contains(&rec_type.value().as_str()))
+        else {
+            return Vec::new();
+        };
+        self.alarm_limits()
+            .into_iter()
+            .map(|(name, kw, _)| {
+                compile_warning(
+                    syn::spanned::Spanned::span(&kw),
+                    "AsRecordWarning",
+                    &format!(
+                        "`{}` alarm limit is not used by `{}` records",
+                        name.to_lowercase(),
+                        rec_type.value()
+                    ),
+                )
+            })
+            .collect()
+    }
+
+    /// In `strict_type_check` mode warns about calc records without a `CALC` field and about
+    /// input variables of `calc_expr` without an `INPx` (or constant `x`) field. It is not an
+    /// error, since the fields can also be set at runtime (e.g. by `dbpf`).
+    fn type_check_warnings(&self) -> Vec<proc_macro2::TokenStream> {
+        if self.strict_type_check.is_none() {
+            return Vec::new();
+        }
+        let is_calc = |rec_type: &LitStr| CALC_REC_TYPES.contains(&rec_type.value().as_str());
+        let missing_calc = |rec_type: &LitStr| {
+            compile_warning(
+                rec_type.span(),
+                "MissingRecordField",
+                &format!(
+                    "`{}` record has no CALC field, add `calc_expr` or a `field = \"CALC\"` member",
+                    rec_type.value()
+                ),
+            )
+        };
+        let mut res = Vec::new();
+        match &self.type_rec_type {
+            Some((_, rec_type)) if is_calc(rec_type) => {
+                let field_names: Vec<String> = self
+                    .fields
+                    .iter()
+                    .filter_map(|field| field.field_name.as_ref().map(|(_, val)| val.value()))
+                    .collect();
+                match &self.calc_expr {
+                    Some((kw, expr)) => {
+                        for var in calc_expr_inputs(&expr.value()) {
+                            let inp = format!("INP{}", var);
+                            if !field_names.contains(&inp) && !field_names.contains(&var.to_string())
+                            {
+                                res.push(compile_warning(
+                                    kw.span,
+                                    "MissingRecordField",
+                                    &format!(
+                                        "calc expression uses `{}`, but neither `{}` nor `{}` field is defined",
+                                        var, inp, var
+                                    ),
+                                ));
+                            }
+                        }
+                    }
+                    None if !field_names.iter().any(|name| name == "CALC") => {
+                        res.push(missing_calc(rec_type));
+                    }
+                    None => (),
+                }
+            }
+            Some(_) => (),
+            // Every member is a separate record in multi-record mode
+            None if self.calc_expr.is_none() => {
+                for field in &self.fields {
+                    let Some((_, rec_type)) = &field.rec_type else {
+                        continue;
+                    };
+                    let is_calc_field = field
+                        .field_name
+                        .as_ref()
+                        .is_some_and(|(_, val)| val.value() == "CALC");
+                    if is_calc(rec_type) && !is_calc_field {
+                        res.push(missing_calc(rec_type));
+                    }
+                }
+            }
+            None => (),
+        }
+        res
+    }
+
+    /// Keyword of the record definitions, `record` or `grecord`.
+    fn rec_syntax(&self) -> String {
+        self.rec_syntax
+            .as_ref()
+            .map(|(_, val)| val.value())
+            .unwrap_or_else(|| "record".to_string())
+    }
+
+    /// Returns the record name with the `global_prefix` prepended and `global_suffix` appended.
+    /// The length of the resulting name is checked.
+    fn rec_name(&self, rec_name: &LitStr) -> syn::Result<String> {
+        let mut res = rec_name.value();
+        if let Some((kw, prefix)) = &self.global_prefix {
+            if res.starts_with(&prefix.value()) {
+                let mut err = syn::Error::new_spanned(
+                    rec_name,
+                    format!(
+                        "rec_name already starts with the global prefix `{}`",
+                        prefix.value()
+                    ),
+                );
+                err.combine(syn::Error::new_spanned(kw, "global prefix defined here"));
+                return Err(err);
+            }
+            res.insert_str(0, &prefix.value());
+        }
+        if let Some((kw, suffix)) = &self.global_suffix {
+            if res.ends_with(&suffix.value()) {
+                let mut err = syn::Error::new_spanned(
+                    rec_name,
+                    format!(
+                        "rec_name already ends with the global suffix `{}`",
+                        suffix.value()
+                    ),
+                );
+                err.combine(syn::Error::new_spanned(kw, "global suffix defined here"));
+                return Err(err);
+            }
+            res.push_str(&suffix.value());
+        }
+        check_rec_name_len(rec_name, &res)?;
+        Ok(res)
+    }
+
+    /// Checks that the fields defined by struct level attributes are not defined again on a member.
+    fn check_shortcut_fields(&self) -> syn::Result<()> {
+        let mut shortcuts: Vec<(String, proc_macro2::TokenStream)> = Vec::new();
+        if let Some((kw, _)) = &self.desc {
+            shortcuts.push(("DESC".to_string(), kw.to_token_stream()));
+        }
+        if let Some((kw, _)) = &self.egu {
+            shortcuts.push(("EGU".to_string(), kw.to_token_stream()));
+        }
+        if let Some((kw, _)) = &self.calc_expr {
+            shortcuts.push(("CALC".to_string(), kw.to_token_stream()));
+        }
+        if let Some((kw, _)) = &self.scan {
+            shortcuts.push(("SCAN".to_string(), kw.to_token_stream()));
+        }
+        if let Some(kw) = &self.pini {
+            shortcuts.push(("PINI".to_string(), kw.to_token_stream()));
+        }
+        for (name, kw, _) in self.alarm_limits() {
+            shortcuts.push((name.to_string(), kw));
+        }
+        for (name, kw, _) in self.mbb_states() {
+            shortcuts.push((name, kw));
+        }
+        if let Some((kw, _)) = &self.dtyp {
+            shortcuts.push(("DTYP".to_string(), kw.to_token_stream()));
+        }
+
+        for field in &self.fields {
+            let Some((kw, val)) = &field.field_name else {
+                continue;
+            };
+            if let Some((name, shortcut)) = shortcuts.iter().find(|(name, _)| *name == val.value())
+            {
+                let mut err = syn::Error::new_spanned(
+                    kw,
+                    format!("field `{}` is already defined by a struct attribute", name),
+                );
+                err.combine(syn::Error::new_spanned(shortcut, "defined here"));
+                return Err(err);
+            }
+        }
+        Ok(())
+    }
+
     pub fn generate(&self) -> syn::Result<proc_macro2::TokenStream> {
-        match (&self.type_rec_name, &self.type_rec_type) {
-            (Some((_, rname)), Some((_, rtype))) => self.generate_single_record(rname, rtype),
+        let rec_name_kw = match (&self.type_rec_name, &self.rec_name_expr) {
+            (Some((kw1, _)), Some((kw2, _))) => {
+                let mut err = syn::Error::new_spanned(
+                    kw2,
+                    "rec_name_expr cannot be used together with rec_name",
+                );
+                err.combine(syn::Error::new_spanned(kw1, "rec_name defined here"));
+                return Err(err);
+            }
+            (Some((kw, _)), None) => Some(kw.to_token_stream()),
+            (None, Some((kw, _))) => Some(kw.to_token_stream()),
+            (None, None) => None,
+        };
+        match (rec_name_kw, &self.type_rec_type) {
+            (Some(_), Some((_, rtype))) => self.generate_single_record(rtype),
             (None, None) => self.generate_multiple_records(),
             (None, Some((kw, _))) => Err(syn::Error::new_spanned(
                 kw,
-                "global rec_type undefined".to_string(),
+                "global rec_type is defined without rec_name".to_string(),
             )),
-            (Some((kw, _)), None) => Err(syn::Error::new_spanned(
+            (Some(kw), None) => Err(syn::Error::new_spanned(
                 kw,
-                "global rec_type undefined".to_string(),
+                "global rec_name is defined without rec_type".to_string(),
             )),
         }
     }
 
-    fn generate_single_record(
-        &self,
-        rec_name: &LitStr,
-        rec_type: &LitStr,
-    ) -> syn::Result<proc_macro2::TokenStream> {
-        let mut subst = quote! {};
-        let mut idents: Vec<proc_macro2::TokenStream> = Vec::new();
-        // Double curly braces are needed to only print the brace (without formatting). Quadruple
-        // needed because this string is later again used in format! macro
-        let mut record = format!(
-            "record({}, \"{}\") {{{{\n",
-            rec_type.value(),
-            rec_name.value()
-        );
+    /// In `strict` mode every member needs a record attribute that prints it (or `skip`), so typos
+    /// in attribute names don't silently omit the member.
+    fn check_strict(&self) -> syn::Result<()> {
+        let Some(strict) = &self.strict else {
+            return Ok(());
+        };
         for field in &self.fields {
-            // Because we're in Global record mode `field_rec_name` and field_rec_type must be
-            // None
-            // Handle errors if `rec_name` or `rec_type` is set
-            match (&field.rec_name, &field.rec_type) {
-                (None, None) => (),
+            let annotated = field.skip
+                || field.field_name.is_some()
+                || field.format.is_some()
+                || field.rec_name.is_some()
+                || !field.subst.is_empty();
+            if !annotated {
+                let mut err = syn::Error::new_spanned(
+                    &field.ident,
+                    "member has no record attribute, add `#[record(skip)]` to omit it",
+                );
+                err.combine(syn::Error::new_spanned(strict, "strict mode enabled here"));
+                return Err(err);
+            }
+        }
+        Ok(())
+    }
+
+    /// Joins the record format strings with the `fmt_sep` separator. Every record ends with a
+    /// newline, which is replaced by the separator.
+    fn join_records(&self, records: &[String]) -> String {
+        let Some((_, sep)) = &self.fmt_sep else {
+            return records.concat();
+        };
+        let mut res = records
+            .iter()
+            .map(|record| record.strip_suffix('\n').unwrap_or(record))
+            .collect::<Vec<_>>()
+            .join(&escape_braces(&sep.value()));
+        if !records.is_empty() {
+            res.push('\n');
+        }
+        res
+    }
+
+    /// Companion records (`also_rec_name`, `also_rec_type`) with empty bodies, as a part of a
+    /// format string.
+    fn also_records(&self) -> syn::Result<String> {
+        let mut res = String::new();
+        let mut names = self.also_rec_names.iter();
+        let mut types = self.also_rec_types.iter();
+        loop {
+            match (names.next(), types.next()) {
+                (Some((_, name)), Some((_, rec_type))) => res.push_str(&format!(
+                    "{}({}, \"{}\") {{{{\n}}}}\n",
+                    self.rec_syntax(),
+                    rec_type.value(),
+                    self.rec_name(name)?
+                )),
+                (Some((kw, _)), None) => {
+                    return Err(syn::Error::new_spanned(
+                        kw,
+                        "also_rec_name is missing a matching also_rec_type",
+                    ))
+                }
                 (None, Some((kw, _))) => {
                     return Err(syn::Error::new_spanned(
                         kw,
-                        "rec_type cannot be set when the global rec_type exists".to_string(),
-                    ));
+                        "also_rec_type is missing a matching also_rec_name",
+                    ))
+                }
+                (None, None) => return Ok(res),
+            }
+        }
+    }
+
+    /// Substitutions of the struct level `subst_field`/`subst_pattern` pairs. Unlike the member
+    /// `subst` attribute, the substituted member is still printed as any other member.
+    fn struct_substs(&self) -> syn::Result<Vec<proc_macro2::TokenStream>> {
+        let mut res = Vec::new();
+        let mut fields = self.subst_fields.iter();
+        let mut patterns = self.subst_patterns.iter();
+        loop {
+            match (fields.next(), patterns.next()) {
+                (Some((_, field)), Some((_, pattern))) => {
+                    let Some(ident) = self
+                        .fields
+                        .iter()
+                        .map(|field_props| &field_props.ident)
+                        .find(|ident| *ident == field.value().as_str())
+                    else {
+                        return Err(syn::Error::new_spanned(
+                            field,
+                            format!("struct has no member named `{}`", field.value()),
+                        ));
+                    };
+                    let pattern = pattern.value();
+                    res.push(quote! {
+                        let res = res.replace(&#pattern, &self.#ident.to_string());
+                    });
                 }
                 (Some((kw, _)), None) => {
                     return Err(syn::Error::new_spanned(
                         kw,
-                        "rec_name cannot be set when the global rec_type exists".to_string(),
-                    ));
+                        "subst_field is missing a matching subst_pattern",
+                    ))
                 }
-                (Some((kw1, _)), Some((kw2, _))) => {
-                    let mut err = syn::Error::new_spanned(
-                        kw1,
-                        "rec_name cannot be set when the global rec_type exists".to_string(),
-                    );
-                    err.combine(syn::Error::new_spanned(
-                        kw2,
-                        "rec_name cannot be set when the global rec type exists",
-                    ));
-                    return Err(err);
+                (None, Some((kw, _))) => {
+                    return Err(syn::Error::new_spanned(
+                        kw,
+                        "subst_pattern is missing a matching subst_field",
+                    ))
                 }
+                (None, None) => return Ok(res),
+            }
+        }
+    }
+
+    /// Returns the global record name as a part of a format string and the format argument of the
+    /// name if it is computed at runtime (`rec_name_expr`).
+    fn global_rec_name(&self) -> syn::Result<(String, Option<proc_macro2::TokenStream>)> {
+        if let Some((_, val)) = &self.rec_name_expr {
+            let prefix = self
+                .global_prefix
+                .as_ref()
+                .map(|(_, prefix)| escape_braces(&prefix.value()))
+                .unwrap_or_default();
+            let suffix = self
+                .global_suffix
+                .as_ref()
+                .map(|(_, suffix)| escape_braces(&suffix.value()))
+                .unwrap_or_default();
+            return Ok((format!("{}{{}}{}", prefix, suffix), Some(val.parse()?)));
+        }
+        match &self.type_rec_name {
+            Some((_, rec_name)) => Ok((self.rec_name(rec_name)?, None)),
+            None => Ok((String::new(), None)),
+        }
+    }
+
+    fn generate_single_record(&self, rec_type: &LitStr) -> syn::Result<proc_macro2::TokenStream> {
+        let mut substs: Vec<proc_macro2::TokenStream> = self.struct_substs()?;
+        let mut idents: Vec<proc_macro2::TokenStream> = Vec::new();
+        // Format string of every field line and its `field_if` condition
+        let mut lines: Vec<String> = Vec::new();
+        let mut conds: Vec<Option<proc_macro2::TokenStream>> = Vec::new();
+        // Double curly braces are needed to only print the brace (without formatting). Quadruple
+        // needed because this string is later again used in format! macro
+        let (rec_name, rec_name_arg) = self.global_rec_name()?;
+        let header = format!(
+            "{}{}({}, \"{}\") {{{{\n{}",
+            comment_lines(&self.comments, ""),
+            self.rec_syntax(),
+            rec_type.value(),
+            rec_name,
+            self.common_fields()
+        );
+
+        let footer = format!("}}}}\n{}", self.also_records()?);
+        for field in &self.fields {
+            // Because we're in Global record mode `field_rec_name` and field_rec_type must be
+            // None
+            // Handle errors if `rec_name` or `rec_type` is set
+            let mut conflict: Option<syn::Error> = None;
+            let field_attrs = [
+                field.rec_name.as_ref().map(|(kw, _)| (kw.to_token_stream(), "rec_name")),
+                field.rec_type.as_ref().map(|(kw, _)| (kw.to_token_stream(), "rec_type")),
+            ];
+            for (kw, attr) in field_attrs.into_iter().flatten() {
+                let err = syn::Error::new_spanned(
+                    kw,
+                    format!("field-level `{}` conflicts with the struct-level record", attr),
+                );
+                match &mut conflict {
+                    Some(conflict) => conflict.combine(err),
+                    None => conflict = Some(err),
+                }
+            }
+            if let Some(err) = conflict {
+                return Err(err);
+            }
+
+            // Handle `skip` attribute
+            if field.skip {
+                continue;
             }
 
             let ident = &field.ident;
 
             // Handle `subst` attribute
-            if let Some((_, val)) = &field.subst {
-                let value = val.value();
-                subst = quote! {
-                    let res = res.replace(&#value, &self.#ident.to_string());
-                };
+            if !field.subst.is_empty() {
+                for (_, val) in &field.subst {
+                    let value = val.value();
+                    substs.push(quote! {
+                        let res = res.replace(&#value, &self.#ident.to_string());
+                    });
+                }
                 continue;
             }
             // Handle `repr` attribute
-            let ident_repr = if let Some((_, val)) = &field.repr {
-                syn::parse_str::<proc_macro2::TokenStream>(&format!(
-                    "self.{} as {}",
-                    ident,
-                    val.path.get_ident().unwrap()
-                ))
-                .unwrap()
-            } else {
-                syn::parse_str::<proc_macro2::TokenStream>(&format!("self.{}", ident)).unwrap()
-            };
+            let ident_repr = field.value_arg(false);
             // Handle `fmt` attribute
-            if let Some((_, val)) = &field.format {
-                record.push_str(&val.value());
-                record.push('\n');
+            let line = if let Some((_, val)) = &field.format {
+                format!("{}\n", val.value())
             // Handle `field` attribute
             } else if let Some((_, val)) = &field.field_name {
-                record.push_str(&format!("  field({}, \"{{}}\")\n", &val.value()));
-            }
+                self.field_line(&val.value(), &field.field_value())
+            } else {
+                // Member is not printed, but it can still be used by e.g. `rec_name_expr` (an
+                // error in strict mode)
+                continue;
+            };
+            lines.push(comment_lines(&field.comments, &self.indent()) + &line);
+            conds.push(field.condition()?);
             idents.push(ident_repr);
         }
-        record.push_str("}}\n");
 
-        Ok(quote! {
-            fn as_record(&self) -> String {
-                let res = format!(
-                    #record,
-                    #(#idents,)*
-                );
-                #subst
-                res
-            }
-        })
+        // Conditional fields can't be a part of a single format string, the record is built at
+        // runtime instead
+        if conds.iter().any(Option::is_some) {
+            let pushes = lines
+                .iter()
+                .zip(&idents)
+                .zip(&conds)
+                .map(|((line, ident), cond)| {
+                    let push = quote! { res.push_str(&format!(#line, #ident)); };
+                    match cond {
+                        Some(cond) => quote! { if #cond { #push } },
+                        None => push,
+                    }
+                });
+            let rec_name_args = rec_name_arg.iter();
+            return Ok(quote! {
+                fn as_record(&self) -> String {
+                    let mut res = format!(#header, #(#rec_name_args)*);
+                    #(#pushes)*
+                    res.push_str(&format!(#footer));
+                    #(#substs)*
+                    res
+                }
+
+                fn as_record_write<W: std::io::Write>(&self, w: &mut W) -> std::io::Result<()> {
+                    w.write_all(self.as_record().as_bytes())
+                }
+            });
+        }
+
+        let record = format!("{}{}{}", header, lines.concat(), footer);
+        // Record name computed at runtime is the first argument of the format string
+        let args: Vec<proc_macro2::TokenStream> = rec_name_arg.into_iter().chain(idents).collect();
+        Ok(generate_as_record(&record, &args, &substs))
     }
 
     fn generate_multiple_records(&self) -> syn::Result<proc_macro2::TokenStream> {
-        let mut subst = quote! {};
+        let mut substs: Vec<proc_macro2::TokenStream> = self.struct_substs()?;
         let mut idents: Vec<proc_macro2::TokenStream> = Vec::new();
-        let mut record = String::new();
+        // Every record is a separate format string, so records can be formatted one by one
+        let mut records: Vec<String> = Vec::new();
+        let mut conds: Vec<Option<proc_macro2::TokenStream>> = Vec::new();
 
-        if self
-            .fields
-            .iter()
-            .all(|field| field.rec_name.is_none() && field.format.is_none())
-        {
+        if let Some((kw, _)) = self.also_rec_names.first() {
+            return Err(syn::Error::new_spanned(
+                kw,
+                "also_rec_name can only be used together with the global rec_name and rec_type",
+            ));
+        }
+
+        // Members with a `field` attribute report the missing `rec_name` themselves, which points
+        // at the attribute instead of the struct
+        if self.fields.iter().all(|field| {
+            field.rec_name.is_none() && field.format.is_none() && field.field_name.is_none()
+        }) {
             return Err(syn::Error::new_spanned(
                 &self.ident,
                 "type cannot be used in this context without defining `rec_name`, `rec_type` or `fmt` attributes"
@@ -270,45 +1638,41 @@ impl TypeProps {
         }
 
         for field in &self.fields {
+            // Handle `skip` attribute
+            if field.skip {
+                continue;
+            }
+
             let ident = &field.ident;
 
             // Handle `subst` attribute
-            if let Some((_, val)) = &field.subst {
-                let value = val.value();
-                subst = quote! {
-                    let res = res.replace(&#value, &self.#ident.to_string());
-                };
+            if !field.subst.is_empty() {
+                for (_, val) in &field.subst {
+                    let value = val.value();
+                    substs.push(quote! {
+                        let res = res.replace(&#value, &self.#ident.to_string());
+                    });
+                }
                 continue;
             }
 
             // Handle `repr` attribute
-            let ident_repr = if let Some((_, val)) = &field.repr {
-                syn::parse_str::<proc_macro2::TokenStream>(&format!(
-                    "self.{}.clone() as {}",
-                    ident,
-                    val.path.get_ident().unwrap()
-                ))
-                .unwrap()
-            } else {
-                syn::parse_str::<proc_macro2::TokenStream>(&format!("self.{}.clone()", ident))
-                    .unwrap()
-            };
+            let ident_repr = field.value_arg(true);
 
             // Handle `fmt` attribute
-            if let Some((_, val)) = &field.format {
-                record.push_str(&val.value());
-                record.push('\n');
+            let record = if let Some((_, val)) = &field.format {
+                format!("{}\n", val.value())
             // Handle `field` attribute
             } else if let Some((kw, val)) = &field.field_name {
                 match (&field.rec_name, &field.rec_type) {
-                    (Some((_, rec_name)), Some((_, rec_type))) => {
-                        record.push_str(&format!(
-                            "record({}, \"{}\") {{{{\n  field({}, \"{{}}\")\n}}}}\n",
-                            rec_type.value(),
-                            rec_name.value(),
-                            val.value()
-                        ));
-                    }
+                    (Some((_, rec_name)), Some((_, rec_type))) => format!(
+                        "{}({}, \"{}\") {{{{\n{}{}}}}}\n",
+                        self.rec_syntax(),
+                        rec_type.value(),
+                        self.rec_name(rec_name)?,
+                        self.common_fields(),
+                        self.field_line(&val.value(), &field.field_value())
+                    ),
                     (None, None) => {
                         return Err(syn::Error::new_spanned(
                             kw,
@@ -323,32 +1687,133 @@ impl TypeProps {
                         err.combine(syn::Error::new_spanned(kw_type, "rec_type defined here"));
                         return Err(err);
                     }
-                    (Some((kw_name, _)), None) => {
-                        let mut err = syn::Error::new_spanned(
-                            kw,
-                            "field cannot be used in this context without defining rec_name, rec_type".to_string(),
-                        );
-                        err.combine(syn::Error::new_spanned(kw_name, "rec_name defined here"));
-                        return Err(err);
+                    (Some((kw_name, _)), None) => {
+                        let mut err = syn::Error::new_spanned(
+                            kw,
+                            "field cannot be used in this context without defining rec_name, rec_type".to_string(),
+                        );
+                        err.combine(syn::Error::new_spanned(kw_name, "rec_name defined here"));
+                        return Err(err);
+                    }
+                }
+            } else {
+                // Member without a record attribute produces no record (an error in strict mode)
+                continue;
+            };
+            records.push(
+                comment_lines(&self.comments, "") + &comment_lines(&field.comments, "") + &record,
+            );
+            conds.push(field.condition()?);
+            idents.push(ident_repr);
+        }
+
+        let subst = quote! { #(#substs)* };
+
+        // Conditional records are collected at runtime, `as_record` joins them
+        if conds.iter().any(Option::is_some) {
+            // Every record ends with a newline, which is replaced by the separator
+            let join_records = match &self.fmt_sep {
+                Some((_, sep)) => quote! {
+                    let records = self.as_records();
+                    let mut res = records
+                        .iter()
+                        .map(|record| record.strip_suffix('\n').unwrap_or(record))
+                        .collect::<Vec<_>>()
+                        .join(#sep);
+                    if !records.is_empty() {
+                        res.push('\n');
+                    }
+                    res
+                },
+                None => quote! { self.as_records().concat() },
+            };
+            let pushes = records
+                .iter()
+                .zip(&idents)
+                .zip(&conds)
+                .map(|((record, ident), cond)| {
+                    let push = quote! {
+                        let res = format!(#record, #ident);
+                        #subst
+                        records.push(res);
+                    };
+                    match cond {
+                        Some(cond) => quote! { if #cond { #push } },
+                        None => quote! { { #push } },
                     }
+                });
+            return Ok(quote! {
+                fn as_record(&self) -> String {
+                    #join_records
                 }
-            }
-            idents.push(ident_repr);
+
+                fn as_record_write<W: std::io::Write>(&self, w: &mut W) -> std::io::Result<()> {
+                    w.write_all(self.as_record().as_bytes())
+                }
+
+                fn as_records(&self) -> Vec<String> {
+                    let mut records = Vec::new();
+                    #(#pushes)*
+                    records
+                }
+            });
         }
 
+        let as_record = generate_as_record(&self.join_records(&records), &idents, &substs);
+
         Ok(quote! {
-            fn as_record(&self) -> String {
-                let res = format!(
-                    #record,
-                    #(#idents,)*
-                );
-                #subst
-                res
+            #as_record
+
+            fn as_records(&self) -> Vec<String> {
+                vec![
+                    #({
+                        let res = format!(#records, #idents);
+                        #subst
+                        res
+                    },)*
+                ]
             }
         })
     }
 }
 
+/// Generates `as_record` and `as_record_write` methods from a format string and its arguments.
+fn generate_as_record(
+    record: &str,
+    idents: &[proc_macro2::TokenStream],
+    substs: &[proc_macro2::TokenStream],
+) -> proc_macro2::TokenStream {
+    // Substitutions are done on the whole output, so the record can't be written directly
+    let write_body = if substs.is_empty() {
+        quote! {
+            write!(
+                w,
+                #record,
+                #(#idents,)*
+            )
+        }
+    } else {
+        quote! {
+            w.write_all(self.as_record().as_bytes())
+        }
+    };
+
+    quote! {
+        fn as_record(&self) -> String {
+            let res = format!(
+                #record,
+                #(#idents,)*
+            );
+            #(#substs)*
+            res
+        }
+
+        fn as_record_write<W: std::io::Write>(&self, w: &mut W) -> std::io::Result<()> {
+            #write_body
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 struct FieldProps {
     /// field identifier
@@ -357,15 +1822,44 @@ struct FieldProps {
     pub rec_name: Option<(kw::rec_name, LitStr)>,
     /// `rec_type` attribute appearing at the field(member) level
     pub rec_type: Option<(kw::rec_type, LitStr)>,
-    /// EPICS record field name
-    pub field_name: Option<(kw::field, LitStr)>,
+    /// EPICS record field name (`field` or `link_field` keyword)
+    pub field_name: Option<(proc_macro2::TokenStream, LitStr)>,
+    /// the field is an EPICS link (`link_field`), printed as `"<link_target> <link_modifier>"`
+    pub link: bool,
+    /// target of the link, `{}` is replaced by the member value. Defaults to the member value
+    pub link_target: Option<(kw::link_target, LitStr)>,
+    /// process passive and maximize severity flags appended to the link (e.g. `PP MS`)
+    pub link_modifier: Option<(kw::link_modifier, LitStr)>,
     /// overriding format specifier
     pub format: Option<(kw::fmt, LitStr)>,
     /// field value representation. e.g. repr = u8 will result in `format("field(DESC, {} as u8", val)`
-    pub repr: Option<(kw::repr, TypePath)>,
-    /// subst pattern, every record name or field can have a pattern substituted by this field
+    /// (`repr` or `repr_expr` keyword)
+    pub repr: Option<(proc_macro2::TokenStream, ReprKind)>,
+    /// subst patterns, every record name or field can have a pattern substituted by this field
     /// member
-    pub subst: Option<(kw::subst, LitStr)>,
+    pub subst: Vec<(kw::subst, LitStr)>,
+    /// field is omitted from the record output
+    pub skip: bool,
+    /// number of decimal places used when printing the value
+    pub precision: Option<(kw::precision, LitInt)>,
+    /// print the value in hexadecimal notation (`0x1a`)
+    pub hex: bool,
+    /// print the value in binary notation (`0b11010`)
+    pub bin: bool,
+    /// minimum width of the printed value, padded with spaces
+    pub width: Option<(kw::width, LitInt)>,
+    /// alignment of the value within `width` (`left`, `right` or `center`)
+    pub align: Option<(kw::align, LitStr)>,
+    /// condition evaluated at runtime, the field (or record) is only printed if it holds
+    pub field_if: Option<(kw::field_if, LitStr)>,
+    /// the value is printed without the surrounding double quotes (e.g. `field(FLNK, PP(rec))`)
+    pub no_quote: bool,
+    /// `comment` attributes, `# <comment>` lines printed before the field (or its record)
+    pub comments: Vec<(kw::comment, LitStr)>,
+    /// value printed when the `Option` member is `None`
+    pub default_val: Option<(kw::default, LitStr)>,
+    /// path of the file the `fmt` format string is read from, relative to the crate root
+    pub fmt_file: Option<(kw::fmt_file, LitStr)>,
 }
 
 impl FieldProps {
@@ -375,9 +1869,164 @@ impl FieldProps {
             rec_name: Default::default(),
             rec_type: Default::default(),
             field_name: Default::default(),
+            link: Default::default(),
+            link_target: Default::default(),
+            link_modifier: Default::default(),
             format: Default::default(),
             repr: Default::default(),
             subst: Default::default(),
+            skip: Default::default(),
+            precision: Default::default(),
+            hex: Default::default(),
+            bin: Default::default(),
+            width: Default::default(),
+            align: Default::default(),
+            field_if: Default::default(),
+            no_quote: Default::default(),
+            comments: Default::default(),
+            default_val: Default::default(),
+            fmt_file: Default::default(),
+        }
+    }
+
+    /// Parses the `field_if` condition.
+    fn condition(&self) -> syn::Result<Option<proc_macro2::TokenStream>> {
+        match &self.field_if {
+            Some((_, val)) => Ok(Some(val.parse()?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Format argument of the member value, with the `repr` conversion applied. The member is
+    /// cloned when every member prints its own record.
+    fn value_arg(&self, clone: bool) -> proc_macro2::TokenStream {
+        let ident = &self.ident;
+        // The member is still read, so it isn't reported as dead code
+        if !self.link_uses_value() {
+            return quote! { { let _ = &self.#ident; "" } };
+        }
+        // `Some` value is formatted in place, so `None` can be replaced by the default string
+        if let Some((_, default)) = &self.default_val {
+            let spec = self.format_spec();
+            let value = self.repr_value(quote! { val }, quote! { *val });
+            return quote! {
+                match &self.#ident {
+                    Some(val) => format!(#spec, #value),
+                    None => #default.to_string(),
+                }
+            };
+        }
+        let value = if clone {
+            quote! { self.#ident.clone() }
+        } else {
+            quote! { self.#ident }
+        };
+        self.repr_value(quote! { self.#ident }, value)
+    }
+
+    /// Applies the `repr` conversion to the member value. `place` refers to the value, `value` is
+    /// the value itself (e.g. a clone).
+    fn repr_value(
+        &self,
+        place: proc_macro2::TokenStream,
+        value: proc_macro2::TokenStream,
+    ) -> proc_macro2::TokenStream {
+        match &self.repr {
+            Some((_, ReprKind::TypeCast(ty))) => quote! { #value as #ty },
+            Some((_, ReprKind::Display)) => quote! { format!("{}", #place) },
+            Some((_, ReprKind::Debug)) => quote! { format!("{:?}", #place) },
+            Some((_, ReprKind::Expr(expr))) => quote! { (#expr) },
+            None => value,
+        }
+    }
+
+    /// Format specifier of the format argument. The `Option` member with a `default` is already
+    /// formatted by [`value_arg`](Self::value_arg).
+    fn arg_spec(&self) -> String {
+        if self.default_val.is_some() {
+            "{}".to_string()
+        } else {
+            self.format_spec()
+        }
+    }
+
+    /// Returns the value part of the `field(<name>, <value>)` line, quoted unless `no_quote` is set.
+    fn field_value(&self) -> String {
+        if self.no_quote {
+            self.value_spec()
+        } else {
+            format!("\"{}\"", self.value_spec())
+        }
+    }
+
+    /// Returns the format string of the field value, the link format for `link_field`.
+    fn value_spec(&self) -> String {
+        if self.link {
+            self.link_spec()
+        } else {
+            self.arg_spec()
+        }
+    }
+
+    /// Returns false for links with a constant `link_target`, which don't print the member value.
+    fn link_uses_value(&self) -> bool {
+        match &self.link_target {
+            Some((_, val)) if self.link => val.value().contains("{}"),
+            _ => true,
+        }
+    }
+
+    /// Returns the format string of a link, `<link_target> <link_modifier>`. A constant target is
+    /// followed by an empty placeholder, so the format string still has exactly one argument.
+    fn link_spec(&self) -> String {
+        let mut spec = match &self.link_target {
+            Some((_, val)) => val
+                .value()
+                .split("{}")
+                .map(escape_braces)
+                .collect::<Vec<_>>()
+                .join(&self.arg_spec()),
+            None => self.arg_spec(),
+        };
+        if !self.link_uses_value() {
+            spec.push_str("{}");
+        }
+        if let Some((_, val)) = &self.link_modifier {
+            spec.push(' ');
+            spec.push_str(&escape_braces(&val.value()));
+        }
+        spec
+    }
+
+    /// Returns the format specifier (e.g. `{}`, `{:.2}`, `{:#x}` or `{:<40}`) used to print the
+    /// field value.
+    fn format_spec(&self) -> String {
+        let mut spec = String::new();
+        if let Some((_, val)) = &self.align {
+            spec.push(match val.value().as_str() {
+                "left" => '<',
+                "center" => '^',
+                _ => '>',
+            });
+        }
+        if self.hex || self.bin {
+            spec.push('#');
+        }
+        if let Some((_, val)) = &self.width {
+            spec.push_str(val.base10_digits());
+        }
+        if let Some((_, val)) = &self.precision {
+            spec.push_str(&format!(".{}", val.base10_digits()));
+        }
+        if self.hex {
+            spec.push('x');
+        } else if self.bin {
+            spec.push('b');
+        }
+        if spec.is_empty() {
+            "{}".to_string()
+        } else {
+            format!("{{:{}}}", spec)
         }
     }
 }
@@ -385,7 +2034,7 @@ impl FieldProps {
 // documentation for more details.
 //
 /// Parses a collection of Attributes and collects it into a collection of a user defined type T.
-fn get_metadata_inner<'a, T: Parse>(
+pub(crate) fn get_metadata_inner<'a, T: Parse>(
     ident: &str,
     it: impl IntoIterator<Item = &'a Attribute>,
 ) -> syn::Result<Vec<T>> {
@@ -401,6 +2050,35 @@ fn get_metadata_inner<'a, T: Parse>(
 enum StructMeta {
     RecName { kw: kw::rec_name, val: syn::LitStr },
     RecType { kw: kw::rec_type, val: syn::LitStr },
+    Pini { kw: kw::pini },
+    Scan { kw: kw::scan, val: syn::LitStr },
+    Dtyp { kw: kw::dtyp, val: syn::LitStr },
+    Desc { kw: kw::desc, val: syn::LitStr },
+    Egu { kw: kw::egu, val: syn::LitStr },
+    CalcExpr { kw: kw::calc_expr, val: syn::LitStr },
+    Lolo { kw: kw::lolo, val: AlarmLimit },
+    Low { kw: kw::low, val: AlarmLimit },
+    High { kw: kw::high, val: AlarmLimit },
+    Hihi { kw: kw::hihi, val: AlarmLimit },
+    MbbiStates { kw: kw::mbbi_states, val: LitArray<LitStr> },
+    MbbiValues { kw: kw::mbbi_values, val: LitArray<LitInt> },
+    GlobalPrefix { kw: kw::global_prefix, val: syn::LitStr },
+    GlobalSuffix { kw: kw::global_suffix, val: syn::LitStr },
+    RecNameExpr { kw: kw::rec_name_expr, val: syn::LitStr },
+    AlsoRecName { kw: kw::also_rec_name, val: syn::LitStr },
+    AlsoRecType { kw: kw::also_rec_type, val: syn::LitStr },
+    SubstField { kw: kw::subst_field, val: syn::LitStr },
+    SubstPattern { kw: kw::subst_pattern, val: syn::LitStr },
+    FmtSep { kw: kw::fmt_sep, val: syn::LitStr },
+    Strict { kw: kw::strict },
+    StrictTypeCheck { kw: kw::strict_type_check },
+    RecSyntax { kw: kw::rec_syntax, val: syn::LitStr },
+    Bound { kw: kw::bound, val: syn::LitStr },
+    AsRecordFn { kw: kw::as_record_fn, val: syn::LitStr },
+    ImplDisplay { kw: kw::impl_display },
+    Comment { kw: kw::comment, val: syn::LitStr },
+    Indent { kw: kw::indent, val: IndentKind },
+    PadFields { kw: kw::pad_fields, val: syn::LitBool },
 }
 
 impl Parse for StructMeta {
@@ -417,20 +2095,310 @@ impl Parse for StructMeta {
             let _: Token![=] = input.parse()?;
             let val = input.parse()?;
             Ok(StructMeta::RecType { kw, val })
+        } else if lookahead.peek(kw::pini) {
+            let kw = input.parse()?;
+            Ok(StructMeta::Pini { kw })
+        } else if lookahead.peek(kw::scan) {
+            let kw = input.parse()?;
+            let _: Token![=] = input.parse()?;
+            let val = input.parse()?;
+            Ok(StructMeta::Scan { kw, val })
+        } else if lookahead.peek(kw::dtyp) {
+            let kw = input.parse()?;
+            let _: Token![=] = input.parse()?;
+            let val = input.parse()?;
+            Ok(StructMeta::Dtyp { kw, val })
+        } else if lookahead.peek(kw::desc) {
+            let kw = input.parse()?;
+            let _: Token![=] = input.parse()?;
+            let val = input.parse()?;
+            Ok(StructMeta::Desc { kw, val })
+        } else if lookahead.peek(kw::egu) {
+            let kw = input.parse()?;
+            let _: Token![=] = input.parse()?;
+            let val = input.parse()?;
+            Ok(StructMeta::Egu { kw, val })
+        } else if lookahead.peek(kw::calc_expr) {
+            let kw = input.parse()?;
+            let _: Token![=] = input.parse()?;
+            let val = input.parse()?;
+            Ok(StructMeta::CalcExpr { kw, val })
+        } else if lookahead.peek(kw::lolo) {
+            let kw = input.parse()?;
+            let _: Token![=] = input.parse()?;
+            let val = input.parse()?;
+            Ok(StructMeta::Lolo { kw, val })
+        } else if lookahead.peek(kw::low) {
+            let kw = input.parse()?;
+            let _: Token![=] = input.parse()?;
+            let val = input.parse()?;
+            Ok(StructMeta::Low { kw, val })
+        } else if lookahead.peek(kw::high) {
+            let kw = input.parse()?;
+            let _: Token![=] = input.parse()?;
+            let val = input.parse()?;
+            Ok(StructMeta::High { kw, val })
+        } else if lookahead.peek(kw::hihi) {
+            let kw = input.parse()?;
+            let _: Token![=] = input.parse()?;
+            let val = input.parse()?;
+            Ok(StructMeta::Hihi { kw, val })
+        } else if lookahead.peek(kw::mbbi_states) {
+            let kw = input.parse()?;
+            let _: Token![=] = input.parse()?;
+            let val = input.parse()?;
+            Ok(StructMeta::MbbiStates { kw, val })
+        } else if lookahead.peek(kw::mbbi_values) {
+            let kw = input.parse()?;
+            let _: Token![=] = input.parse()?;
+            let val = input.parse()?;
+            Ok(StructMeta::MbbiValues { kw, val })
+        } else if lookahead.peek(kw::global_prefix) {
+            let kw = input.parse()?;
+            let _: Token![=] = input.parse()?;
+            let val = input.parse()?;
+            Ok(StructMeta::GlobalPrefix { kw, val })
+        } else if lookahead.peek(kw::global_suffix) {
+            let kw = input.parse()?;
+            let _: Token![=] = input.parse()?;
+            let val = input.parse()?;
+            Ok(StructMeta::GlobalSuffix { kw, val })
+        } else if lookahead.peek(kw::rec_name_expr) {
+            let kw = input.parse()?;
+            let _: Token![=] = input.parse()?;
+            let val = input.parse()?;
+            Ok(StructMeta::RecNameExpr { kw, val })
+        } else if lookahead.peek(kw::also_rec_name) {
+            let kw = input.parse()?;
+            let _: Token![=] = input.parse()?;
+            let val = input.parse()?;
+            Ok(StructMeta::AlsoRecName { kw, val })
+        } else if lookahead.peek(kw::also_rec_type) {
+            let kw = input.parse()?;
+            let _: Token![=] = input.parse()?;
+            let val = input.parse()?;
+            Ok(StructMeta::AlsoRecType { kw, val })
+        } else if lookahead.peek(kw::subst_field) {
+            let kw = input.parse()?;
+            let _: Token![=] = input.parse()?;
+            let val = input.parse()?;
+            Ok(StructMeta::SubstField { kw, val })
+        } else if lookahead.peek(kw::subst_pattern) {
+            let kw = input.parse()?;
+            let _: Token![=] = input.parse()?;
+            let val = input.parse()?;
+            Ok(StructMeta::SubstPattern { kw, val })
+        } else if lookahead.peek(kw::fmt_sep) {
+            let kw = input.parse()?;
+            let _: Token![=] = input.parse()?;
+            let val = input.parse()?;
+            Ok(StructMeta::FmtSep { kw, val })
+        } else if lookahead.peek(kw::strict) {
+            let kw = input.parse()?;
+            Ok(StructMeta::Strict { kw })
+        } else if lookahead.peek(kw::strict_type_check) {
+            let kw = input.parse()?;
+            Ok(StructMeta::StrictTypeCheck { kw })
+        } else if lookahead.peek(kw::rec_syntax) {
+            let kw = input.parse()?;
+            let _: Token![=] = input.parse()?;
+            let val = input.parse()?;
+            Ok(StructMeta::RecSyntax { kw, val })
+        } else if lookahead.peek(kw::bound) {
+            let kw = input.parse()?;
+            let _: Token![=] = input.parse()?;
+            let val = input.parse()?;
+            Ok(StructMeta::Bound { kw, val })
+        } else if lookahead.peek(kw::as_record_fn) {
+            let kw = input.parse()?;
+            let _: Token![=] = input.parse()?;
+            let val = input.parse()?;
+            Ok(StructMeta::AsRecordFn { kw, val })
+        } else if lookahead.peek(kw::impl_display) {
+            let kw = input.parse()?;
+            Ok(StructMeta::ImplDisplay { kw })
+        } else if lookahead.peek(kw::comment) {
+            let kw = input.parse()?;
+            let _: Token![=] = input.parse()?;
+            let val = input.parse()?;
+            Ok(StructMeta::Comment { kw, val })
+        } else if lookahead.peek(kw::indent) {
+            let kw = input.parse()?;
+            let _: Token![=] = input.parse()?;
+            let val = input.parse()?;
+            Ok(StructMeta::Indent { kw, val })
+        } else if lookahead.peek(kw::pad_fields) {
+            let kw = input.parse()?;
+            let _: Token![=] = input.parse()?;
+            let val = input.parse()?;
+            Ok(StructMeta::PadFields { kw, val })
+        } else {
+            Err(lookahead.error())
+        }
+    }
+}
+
+/// Value of an alarm limit attribute (`lolo`, `low`, `high`, `hihi`), a number or a string (e.g.
+/// an EPICS macro `"$(LOLO)"`)
+#[derive(Debug, Clone)]
+struct AlarmLimit(String);
+
+impl Parse for AlarmLimit {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        if input.peek(LitStr) {
+            let val: LitStr = input.parse()?;
+            return Ok(AlarmLimit(val.value()));
+        }
+        let sign = if input.peek(Token![-]) {
+            let _: Token![-] = input.parse()?;
+            "-"
+        } else {
+            ""
+        };
+        let lookahead = input.lookahead1();
+        if lookahead.peek(LitFloat) {
+            let val: LitFloat = input.parse()?;
+            Ok(AlarmLimit(format!("{}{}", sign, val.base10_digits())))
+        } else if lookahead.peek(LitInt) {
+            let val: LitInt = input.parse()?;
+            Ok(AlarmLimit(format!("{}{}", sign, val.base10_digits())))
+        } else {
+            Err(lookahead.error())
+        }
+    }
+}
+
+/// Value of the `indent` attribute, a number of spaces or a tab (`"\t"`)
+#[derive(Debug, Clone)]
+enum IndentKind {
+    Spaces(usize),
+    Tab,
+}
+
+impl Default for IndentKind {
+    fn default() -> Self {
+        IndentKind::Spaces(2)
+    }
+}
+
+impl IndentKind {
+    /// Prefix of every line inside a record.
+    fn prefix(&self) -> String {
+        match self {
+            IndentKind::Spaces(n) => " ".repeat(*n),
+            IndentKind::Tab => "\t".to_string(),
+        }
+    }
+}
+
+impl Parse for IndentKind {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let lookahead = input.lookahead1();
+        if lookahead.peek(LitInt) {
+            let val: LitInt = input.parse()?;
+            Ok(IndentKind::Spaces(val.base10_parse()?))
+        } else if lookahead.peek(LitStr) {
+            let val: LitStr = input.parse()?;
+            if val.value() != "\t" {
+                return Err(syn::Error::new_spanned(
+                    val,
+                    "expected a number of spaces or \"\\t\"",
+                ));
+            }
+            Ok(IndentKind::Tab)
         } else {
             Err(lookahead.error())
         }
     }
 }
 
+/// Bracketed list of literals (e.g. `["Off", "On"]`)
+#[derive(Debug, Clone)]
+struct LitArray<T> {
+    bracket: syn::token::Bracket,
+    elems: Punctuated<T, syn::token::Comma>,
+}
+
+impl<T> LitArray<T> {
+    /// Checks that the list fits into the 16 states of an mbbi/mbbo record.
+    fn check_mbb_len(&self) -> syn::Result<()> {
+        if self.elems.len() > MBB_STATE_PREFIXES.len() {
+            return Err(syn::Error::new(
+                self.bracket.span.join(),
+                format!(
+                    "mbbi/mbbo records have at most {} states, found {}",
+                    MBB_STATE_PREFIXES.len(),
+                    self.elems.len()
+                ),
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl<T: Parse> Parse for LitArray<T> {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let content;
+        let bracket = syn::bracketed!(content in input);
+        let elems = content.parse_terminated(T::parse, Token![,])?;
+        Ok(LitArray { bracket, elems })
+    }
+}
+
+/// Value of the `repr` attribute
+#[derive(Debug, Clone)]
+enum ReprKind {
+    /// `repr = <type>`, the value is cast to the type
+    TypeCast(TypePath),
+    /// `repr = "display"`, the value is formatted with `Display`
+    Display,
+    /// `repr = "debug"`, the value is formatted with `Debug`
+    Debug,
+    /// `repr_expr = "<expr>"`, the expression is printed instead of the value
+    Expr(proc_macro2::TokenStream),
+}
+
+impl Parse for ReprKind {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        if input.peek(LitStr) {
+            let val: LitStr = input.parse()?;
+            match val.value().as_str() {
+                "display" => Ok(ReprKind::Display),
+                "debug" => Ok(ReprKind::Debug),
+                _ => Err(syn::Error::new_spanned(
+                    val,
+                    "expected a type, \"display\" or \"debug\"",
+                )),
+            }
+        } else {
+            Ok(ReprKind::TypeCast(input.parse()?))
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 enum FieldMeta {
     RecName { kw: kw::rec_name, val: syn::LitStr },
     RecType { kw: kw::rec_type, val: syn::LitStr },
     RecField { kw: kw::field, val: syn::LitStr },
-    Repr { kw: kw::repr, val: syn::TypePath },
+    Repr { kw: kw::repr, val: ReprKind },
+    ReprExpr { kw: kw::repr_expr, val: syn::LitStr },
     Fmt { kw: kw::fmt, val: syn::LitStr },
     Subst { kw: kw::subst, val: syn::LitStr },
+    Skip { kw: kw::skip },
+    Precision { kw: kw::precision, val: syn::LitInt },
+    Hex { kw: kw::hex },
+    Bin { kw: kw::bin },
+    FieldIf { kw: kw::field_if, val: syn::LitStr },
+    Width { kw: kw::width, val: syn::LitInt },
+    Align { kw: kw::align, val: syn::LitStr },
+    NoQuote { kw: kw::no_quote },
+    Comment { kw: kw::comment, val: syn::LitStr },
+    LinkField { kw: kw::link_field, val: syn::LitStr },
+    LinkTarget { kw: kw::link_target, val: syn::LitStr },
+    LinkModifier { kw: kw::link_modifier, val: syn::LitStr },
+    Default { kw: kw::default, val: syn::LitStr },
+    FmtFile { kw: kw::fmt_file, val: syn::LitStr },
 }
 
 impl Parse for FieldMeta {
@@ -462,34 +2430,159 @@ impl Parse for FieldMeta {
             let _: Token![=] = input.parse()?;
             let val = input.parse()?;
             Ok(FieldMeta::Repr { kw, val })
+        } else if lookahead.peek(kw::repr_expr) {
+            let kw = input.parse()?;
+            let _: Token![=] = input.parse()?;
+            let val = input.parse()?;
+            Ok(FieldMeta::ReprExpr { kw, val })
         } else if lookahead.peek(kw::fmt) {
             let kw = input.parse()?;
             let _: Token![=] = input.parse()?;
             let val = input.parse()?;
             Ok(FieldMeta::Fmt { kw, val })
+        } else if lookahead.peek(kw::precision) {
+            let kw = input.parse()?;
+            let _: Token![=] = input.parse()?;
+            let val = input.parse()?;
+            Ok(FieldMeta::Precision { kw, val })
+        } else if lookahead.peek(kw::hex) {
+            let kw = input.parse()?;
+            Ok(FieldMeta::Hex { kw })
+        } else if lookahead.peek(kw::bin) {
+            let kw = input.parse()?;
+            Ok(FieldMeta::Bin { kw })
+        } else if lookahead.peek(kw::skip) {
+            let kw = input.parse()?;
+            Ok(FieldMeta::Skip { kw })
+        } else if lookahead.peek(kw::field_if) {
+            let kw = input.parse()?;
+            let _: Token![=] = input.parse()?;
+            let val = input.parse()?;
+            Ok(FieldMeta::FieldIf { kw, val })
+        } else if lookahead.peek(kw::width) {
+            let kw = input.parse()?;
+            let _: Token![=] = input.parse()?;
+            let val = input.parse()?;
+            Ok(FieldMeta::Width { kw, val })
+        } else if lookahead.peek(kw::align) {
+            let kw = input.parse()?;
+            let _: Token![=] = input.parse()?;
+            let val = input.parse()?;
+            Ok(FieldMeta::Align { kw, val })
+        } else if lookahead.peek(kw::no_quote) {
+            let kw = input.parse()?;
+            Ok(FieldMeta::NoQuote { kw })
+        } else if lookahead.peek(kw::comment) {
+            let kw = input.parse()?;
+            let _: Token![=] = input.parse()?;
+            let val = input.parse()?;
+            Ok(FieldMeta::Comment { kw, val })
+        } else if lookahead.peek(kw::link_field) {
+            let kw = input.parse()?;
+            let _: Token![=] = input.parse()?;
+            let val = input.parse()?;
+            Ok(FieldMeta::LinkField { kw, val })
+        } else if lookahead.peek(kw::link_target) {
+            let kw = input.parse()?;
+            let _: Token![=] = input.parse()?;
+            let val = input.parse()?;
+            Ok(FieldMeta::LinkTarget { kw, val })
+        } else if lookahead.peek(kw::link_modifier) {
+            let kw = input.parse()?;
+            let _: Token![=] = input.parse()?;
+            let val = input.parse()?;
+            Ok(FieldMeta::LinkModifier { kw, val })
+        } else if lookahead.peek(kw::default) {
+            let kw = input.parse()?;
+            let _: Token![=] = input.parse()?;
+            let val = input.parse()?;
+            Ok(FieldMeta::Default { kw, val })
+        } else if lookahead.peek(kw::fmt_file) {
+            let kw = input.parse()?;
+            let _: Token![=] = input.parse()?;
+            let val = input.parse()?;
+            Ok(FieldMeta::FmtFile { kw, val })
         } else {
             Err(lookahead.error())
         }
     }
 }
 
+/// Resolves the `fmt_file` path relative to the root of the crate being compiled.
+fn fmt_file_path(path: &LitStr) -> std::path::PathBuf {
+    let root = std::env::var_os("CARGO_MANIFEST_DIR").unwrap_or_default();
+    std::path::Path::new(&root).join(path.value())
+}
+
+/// Reads the format string of a `fmt_file` attribute. The trailing newline of the file is
+/// removed, since the `fmt` output already ends with one.
+fn read_fmt_file(path: &LitStr) -> syn::Result<LitStr> {
+    let contents = std::fs::read_to_string(fmt_file_path(path)).map_err(|err| {
+        syn::Error::new_spanned(
+            path,
+            format!("could not read `fmt_file` \"{}\": {}", path.value(), err),
+        )
+    })?;
+    let contents = contents
+        .strip_suffix('\n')
+        .map(|contents| contents.strip_suffix('\r').unwrap_or(contents))
+        .unwrap_or(&contents);
+    Ok(LitStr::new(contents, path.span()))
+}
+
+/// Checks whether the member type is an `Option`.
+fn type_is_option(ty: &syn::Type) -> bool {
+    match ty {
+        syn::Type::Path(ty) => ty
+            .path
+            .segments
+            .last()
+            .is_some_and(|seg| seg.ident == "Option"),
+        _ => false,
+    }
+}
+
+/// Escapes curly braces so the string can be used as a part of a format string.
+fn escape_braces(s: &str) -> String {
+    s.replace('{', "{{").replace('}', "}}")
+}
+
 pub fn occurrence_error<T: ToTokens>(fst: T, snd: T, attr: &str) -> syn::Error {
     let mut e = syn::Error::new_spanned(
         snd,
-        format!("Found multiple occurrences of strum({})", attr),
+        format!("Found multiple occurrences of `{}`", attr),
     );
     e.combine(syn::Error::new_spanned(fst, "first one here"));
     e
 }
 
+/// Error of two mutually exclusive attributes (e.g. `repr` and `repr_expr`) used on the same
+/// member.
+fn conflict_error<T: ToTokens>(
+    fst: &proc_macro2::TokenStream,
+    snd: T,
+    snd_attr: &str,
+    fst_attr: &str,
+) -> syn::Error {
+    let mut e = syn::Error::new_spanned(
+        snd,
+        format!("`{}` cannot be used together with `{}`", snd_attr, fst_attr),
+    );
+    e.combine(syn::Error::new_spanned(fst, format!("`{}` defined here", fst_attr)));
+    e
+}
+
 pub fn rec_def_error<T: ToTokens>(fst: T, snd: T, attr: &str) -> syn::Error {
     let mut e = syn::Error::new_spanned(
         snd,
         format!(
-            "Global `{}` attribute defined, overriding is not possible",
-            attr
+            "field-level `{}` conflicts with struct-level `{}`",
+            attr, attr
         ),
     );
-    e.combine(syn::Error::new_spanned(fst, "first one here"));
+    e.combine(syn::Error::new_spanned(
+        fst,
+        format!("struct-level `{}` defined here", attr),
+    ));
     e
 }
@@ -20,6 +20,9 @@ pub fn derive_from_xstring(input: proc_macro::TokenStream) -> proc_macro::TokenS
 
 /// Convenience macro that implements FromXlsxFloat for marked type. It is used to automatically define functions needed
 /// to convert calamine::Data::Float to target type.
+///
+/// Structs need to be newtypes wrapping a float (e.g. `struct Voltage(f64)`) that implement
+/// `TryFrom<f64>`, structs with multiple fields are converted from a whole row with `FromXlsxRow`.
 #[proc_macro_derive(FromXlsxFloat)]
 pub fn derive_from_xfloat(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let ast = syn::parse(input).unwrap();
@@ -28,9 +31,64 @@ pub fn derive_from_xfloat(input: proc_macro::TokenStream) -> proc_macro::TokenSt
         .into()
 }
 
+/// Convenience macro that implements FromXlsxInt for marked type. It is used to automatically define functions needed
+/// to convert calamine::Data::Int (or calamine::Data::Float without a fractional part, or a string
+/// containing an integer) to target type. The conversion is the same as the one of `i64`, so the
+/// target type has to implement `TryFrom<i64>`.
+#[proc_macro_derive(FromXlsxInt)]
+pub fn derive_from_xint(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let ast = syn::parse(input).unwrap();
+    xlsx::impl_derive_from_xint(&ast)
+        .unwrap_or_else(|err| err.into_compile_error())
+        .into()
+}
+
+/// Convenience macro that implements FromXlsxBool for marked type. It is used to automatically define functions needed
+/// to convert calamine::Data::Bool to target type. String cells `YES`/`NO`, `TRUE`/`FALSE` and `1`/`0`
+/// (case-insensitive) are accepted as well. The target type has to implement `TryFrom<bool>`.
+#[proc_macro_derive(FromXlsxBool)]
+pub fn derive_from_xbool(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let ast = syn::parse(input).unwrap();
+    xlsx::impl_derive_from_xbool(&ast)
+        .unwrap_or_else(|err| err.into_compile_error())
+        .into()
+}
+
+/// Convenience macro that implements FromXlsxDate for marked type. It is used to automatically define functions needed
+/// to convert calamine::Data::DateTime to target type. The target type has to implement
+/// `TryFrom<epics_gen::XlsxDateTime>`.
+#[proc_macro_derive(FromXlsxDate)]
+pub fn derive_from_xdate(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let ast = syn::parse(input).unwrap();
+    xlsx::impl_derive_from_xdate(&ast)
+        .unwrap_or_else(|err| err.into_compile_error())
+        .into()
+}
+
 /// Convenience macro that implements FromXlsxRow for marked type. It is used to automatically
-/// convert XlsxRow (`XlsxRow = Vec<calamine::Data>`) to target type (usually a structure).
-#[proc_macro_derive(FromXlsxRow)]
+/// convert XlsxRow (`XlsxRow = Vec<calamine::Data>`) to target type (usually a structure). Both
+/// structs with named members and tuple structs are supported. Unit structs discard the row, so
+/// they can be used as markers where only the presence of a row matters. Structs with empty braces
+/// (`struct Empty {}`) or parentheses are rejected.
+/// A `Vec<T>` member consumes all the remaining cells of the row and has to be the last member.
+/// An `epics_gen::XlsxRowRemainder` member does the same, keyed by the column header names, leaving
+/// out the columns read by the members mapped by index or header name.
+///
+/// Members can be annotated with the `xlsx` attribute:
+///
+/// - `#[xlsx(skip)]`: the member is not parsed from the row, `Default::default()` is used instead.
+/// - `#[xlsx(default = "<expr>")]`: the expression is used when the cell is empty (or instead of
+///   `Default::default()` when the member is skipped).
+/// - `#[xlsx(column = "<header>")]`: the member is read from the column with the given header name.
+///   `#[xlsx(column = <index>)]` reads it from the given zero-based column index instead.
+/// - `#[xlsx(rename = "<header>")]`: sets the header name of the member's column, which is used
+///   for the column lookup.
+/// - `#[xlsx(parse_with = "<path>")]`: the member is converted by a function, which consumes
+///   cells from the front of the remaining row.
+/// - `#[xlsx(as_string)]`: the `String` member also accepts numeric cells (`epics_gen::get_as_string`).
+/// - `#[xlsx(validate = "<expr>")]`: predicate the converted value (bound to `val`) needs to
+///   satisfy.
+#[proc_macro_derive(FromXlsxRow, attributes(xlsx))]
 pub fn derive_from_xlsx_row(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let ast = syn::parse(input).unwrap();
     xlsx::impl_derive_xlsx_row(&ast)
@@ -38,12 +96,20 @@ pub fn derive_from_xlsx_row(input: proc_macro::TokenStream) -> proc_macro::Token
         .into()
 }
 
-/// Implements `as_record` method for struct. See [`as_record attributes`]() for attribute
+/// Implements `AsRecord` trait for struct. See [`as_record attributes`]() for attribute
 /// information.
 ///
-/// Returns struct in form of EPICS records. Usually the `as_record` is implemented by `AsRecord`
-/// derive proc_macro, but if some additional bussiness logic needs to be implemented, the
-/// `as_record` for the target struct can be implemented manually.
+/// Returns struct in form of EPICS records. Usually the `AsRecord` trait is implemented by
+/// `AsRecord` derive proc_macro, but if some additional bussiness logic needs to be implemented,
+/// the trait for the target struct can be implemented manually.
+///
+/// Newtype wrappers (`struct VoltageRecord(VoltageData)`) delegate to the wrapped value, which has
+/// to implement `AsRecord` as well.
+///
+/// Members without a record attribute are left out of the output: a member without `field`
+/// prints no field of the struct's record, and a member without `rec_name` and `rec_type`
+/// produces no record when every member defines its own record. With `#[record(strict)]` such
+/// members are an error, `#[record(skip)]` omits a member explicitly.
 #[proc_macro_derive(AsRecord, attributes(record))]
 pub fn derive_as_record(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let ast = syn::parse_macro_input!(input as DeriveInput);
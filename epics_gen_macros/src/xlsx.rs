@@ -1,4 +1,7 @@
-use quote::quote;
+use quote::{quote, ToTokens};
+use syn::{parse::Parse, Token};
+
+use crate::as_record::{get_metadata_inner, occurrence_error};
 
 pub(super) fn impl_derive_from_xstring(ast: &syn::DeriveInput) -> proc_macro2::TokenStream {
     let id = &ast.ident;
@@ -22,6 +25,22 @@ pub(super) fn impl_derive_from_xfloat(
     ast: &syn::DeriveInput,
 ) -> syn::Result<proc_macro2::TokenStream> {
     let id = &ast.ident;
+    // A single cell only makes sense for a newtype, e.g. `struct Voltage(f64)`
+    if let syn::Data::Struct(syn::DataStruct { fields, .. }) = &ast.data {
+        let is_newtype =
+            matches!(fields, syn::Fields::Unnamed(fields) if fields.unnamed.len() == 1);
+        if !is_newtype {
+            let span = match fields {
+                syn::Fields::Unit => id.to_token_stream(),
+                _ => fields.to_token_stream(),
+            };
+            return Err(syn::Error::new_spanned(
+                span,
+                "FromXlsxFloat is only for newtypes wrapping a float (e.g. `struct Voltage(f64)`), \
+                 use FromXlsxRow for structs with multiple fields",
+            ));
+        }
+    }
     let res = quote! {
         impl FromXlsxData for #id {
             type Error = epics_gen::ParseErrorKind;
@@ -38,74 +57,418 @@ pub(super) fn impl_derive_from_xfloat(
     Ok(res)
 }
 
+pub(super) fn impl_derive_from_xint(
+    ast: &syn::DeriveInput,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let id = &ast.ident;
+    let res = quote! {
+        impl FromXlsxData for #id {
+            type Error = epics_gen::ParseErrorKind;
+
+            fn from_xlsx_data(data: epics_gen::XlsxData) -> Result<Self, Self::Error> {
+                let val = <i64 as epics_gen::FromXlsxData>::from_xlsx_data(data)?;
+                val.try_into().map_err(|_| Self::Error::InvalidValue)
+            }
+        }
+    };
+    Ok(res)
+}
+
+pub(super) fn impl_derive_from_xbool(
+    ast: &syn::DeriveInput,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let id = &ast.ident;
+    let res = quote! {
+        impl FromXlsxData for #id {
+            type Error = epics_gen::ParseErrorKind;
+
+            fn from_xlsx_data(data: epics_gen::XlsxData) -> Result<Self, Self::Error> {
+                let val = <bool as epics_gen::FromXlsxData>::from_xlsx_data(data)?;
+                val.try_into().map_err(|_| Self::Error::InvalidValue)
+            }
+        }
+    };
+    Ok(res)
+}
+
+pub(super) fn impl_derive_from_xdate(
+    ast: &syn::DeriveInput,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let id = &ast.ident;
+    let res = quote! {
+        impl FromXlsxData for #id {
+            type Error = epics_gen::ParseErrorKind;
+
+            fn from_xlsx_data(data: epics_gen::XlsxData) -> Result<Self, Self::Error> {
+                match data {
+                    epics_gen::XlsxData::DateTime(val) => {
+                        val.try_into().map_err(|_| Self::Error::InvalidValue)
+                    }
+                    epics_gen::XlsxData::Empty => Err(Self::Error::ValueMissing),
+                    _ => Err(Self::Error::InvalidValue),
+                }
+            }
+        }
+    };
+    Ok(res)
+}
+
 pub(super) fn impl_derive_xlsx_row(
     ast: &syn::DeriveInput,
 ) -> syn::Result<proc_macro2::TokenStream> {
     let id = &ast.ident;
     //Iterate through all the fields and try to convert them into types and push them into the
     //struct
-    let fields = if let syn::Data::Struct(syn::DataStruct {
-        fields: syn::Fields::Named(syn::FieldsNamed { ref named, .. }),
+    let unit_fields = syn::punctuated::Punctuated::new();
+    let fields = match ast.data {
+        syn::Data::Struct(syn::DataStruct {
+            fields: syn::Fields::Named(syn::FieldsNamed { ref named, .. }),
+            ..
+        }) => named,
+        syn::Data::Struct(syn::DataStruct {
+            fields: syn::Fields::Unnamed(syn::FieldsUnnamed { ref unnamed, .. }),
+            ..
+        }) => unnamed,
+        // Unit struct discards the row, only the presence of the row matters
+        syn::Data::Struct(syn::DataStruct {
+            fields: syn::Fields::Unit,
+            ..
+        }) => &unit_fields,
+        _ => {
+            return Err(syn::Error::new_spanned(
+                ast,
+                "Cannot implement for struct without fields.",
+            ));
+        }
+    };
+    // Unit structs are intentional markers, empty braces or parentheses are most likely a mistake
+    if let syn::Data::Struct(syn::DataStruct {
+        fields: fields @ (syn::Fields::Named(_) | syn::Fields::Unnamed(_)),
         ..
-    }) = ast.data
+    }) = &ast.data
     {
-        named
-    } else {
-        return Err(syn::Error::new_spanned(
-            ast,
-            "Cannot implement for struct without fields.",
-        ));
-    };
+        if fields.is_empty() {
+            return Err(syn::Error::new_spanned(
+                fields,
+                "FromXlsxRow requires at least one field",
+            ));
+        }
+    }
     let mut field_convert = Vec::new();
+    let mut where_predicates = Vec::new();
+    // index of the column that is read by the next positional member
+    let mut col: usize = 0;
+    // number of columns required by the positional and indexed members
+    let mut column_count: usize = 0;
+    // names of the positional members, up to the first member that isn't a single named cell
+    let mut column_names: Vec<String> = Vec::new();
+    let mut column_names_end = false;
+    // set after a `parse_with` member, which consumes a number of cells known only at runtime
+    let mut shifted = false;
+    // `Option<usize>` expressions of the columns read by the indexed and header mapped members,
+    // which are left out of the `XlsxRowRemainder`
+    let mut read_cols: Vec<proc_macro2::TokenStream> = Vec::new();
 
     for (i, field) in fields.iter().enumerate() {
-        let id = &field.ident;
         let ty = &field.ty;
+        let field_props = FieldProps::new(&field.attrs)?;
+        let default = match &field_props.default {
+            Some((_, val)) => Some(val.parse::<syn::Expr>()?),
+            None => None,
+        };
+
+        // Handle `skip` attribute
+        if field_props.skip {
+            let default = match default {
+                Some(default) => quote! { #default },
+                None => {
+                    where_predicates.push(quote! { #ty: Default });
+                    quote! { Default::default() }
+                }
+            };
+            field_convert.push(default);
+            continue;
+        }
+
+        // Handle `parse_with` attribute, the function consumes cells from the front of the
+        // remaining row and the following positional members continue after them
+        if let Some((kw, val)) = &field_props.parse_with {
+            let conflict = [
+                field_props
+                    .default
+                    .as_ref()
+                    .map(|(kw, _)| (quote! { #kw }, "default")),
+                field_props
+                    .column
+                    .as_ref()
+                    .map(|(kw, _)| (quote! { #kw }, "column")),
+                field_props
+                    .rename
+                    .as_ref()
+                    .map(|(kw, _)| (quote! { #kw }, "rename")),
+                field_props
+                    .validate
+                    .as_ref()
+                    .map(|(kw, _)| (quote! { #kw }, "validate")),
+            ];
+            if let Some((attr_kw, attr)) = conflict.into_iter().flatten().next() {
+                let mut err = syn::Error::new_spanned(
+                    attr_kw,
+                    format!("`{}` cannot be used together with `parse_with`", attr),
+                );
+                err.combine(syn::Error::new_spanned(kw, "`parse_with` defined here"));
+                return Err(err);
+            }
+            if field_props.as_string {
+                return Err(syn::Error::new_spanned(
+                    kw,
+                    "`parse_with` cannot be used together with `as_string`",
+                ));
+            }
+            let parse_fn: syn::Path = val.parse()?;
+            let first_col = if shifted {
+                quote! { #col + shift }
+            } else {
+                quote! { #col }
+            };
+            column_names_end = true;
+            shifted = true;
+            field_convert.push(quote! {
+                {
+                    let mut rest: epics_gen::XlsxRow =
+                        row.get((#first_col)..).map(<[_]>::to_vec).unwrap_or_default();
+                    let len = rest.len();
+                    let val = #parse_fn(&mut rest, row_num, table_name)?;
+                    shift += len.saturating_sub(rest.len());
+                    val
+                }
+            });
+            continue;
+        }
+
+        // `Vec` member consumes all the remaining cells of the row
+        let is_vec = extern_type_is(ty, "Vec");
+        if is_vec && i != fields.len() - 1 {
+            return Err(syn::Error::new_spanned(
+                ty,
+                "`Vec` member must be the last member of the struct",
+            ));
+        }
+        // `XlsxRowRemainder` member collects the remaining cells by their header names
+        let is_remainder = type_is_remainder(ty);
+        if is_remainder {
+            if i != fields.len() - 1 {
+                return Err(syn::Error::new_spanned(
+                    ty,
+                    "`XlsxRowRemainder` member must be the last member of the struct",
+                ));
+            }
+            let unsupported = [
+                field_props
+                    .default
+                    .as_ref()
+                    .map(|(kw, _)| (quote! { #kw }, "default")),
+                field_props
+                    .validate
+                    .as_ref()
+                    .map(|(kw, _)| (quote! { #kw }, "validate")),
+            ];
+            if let Some((kw, attr)) = unsupported.into_iter().flatten().next() {
+                return Err(syn::Error::new_spanned(
+                    kw,
+                    format!("`{}` cannot be used on an `XlsxRowRemainder` member", attr),
+                ));
+            }
+            if field_props.as_string {
+                return Err(syn::Error::new_spanned(
+                    ty,
+                    "`as_string` cannot be used on an `XlsxRowRemainder` member",
+                ));
+            }
+        }
 
         let (type_len, ty): (usize, &syn::Type) = match ty {
             syn::Type::Array(syn::TypeArray { elem, .. }) => {
                 let (type_len, _) = destructure_array(ty)?;
-                (type_len.base10_parse().unwrap(), elem)
+                (type_len.base10_parse()?, elem)
             }
+            _ if is_vec => (1, extract_generic_type(ty)?),
             _ => (1, ty),
         };
 
-        let mut single_element = quote! {
-            {
-                let val = row.pop().unwrap();
-                #ty::from_xlsx_data(val.clone()).map_err(|kind| epics_gen::ParseError::new_in_table(kind,epics_gen::XlsxCell::new((row_num as u32, #i as u32), val), table_name.to_owned()))?
+        // Column index expression of the first cell of this member
+        let (col_lookup, first_col) = if let Some(column) = field_props.header_name()? {
+            if !is_vec && !is_remainder {
+                read_cols.extend((0..type_len).map(|i| {
+                    quote! { header.iter().position(|name| name == #column).map(|col| col + #i) }
+                }));
+            }
+            (
+                quote! {
+                    let col = header
+                        .iter()
+                        .position(|name| name == #column)
+                        .ok_or_else(|| epics_gen::ParseError::invalid_column(#column))?;
+                },
+                quote! { col },
+            )
+        } else if let Some(first_col) = field_props.column_index()? {
+            // Indexed members don't consume columns, the same as members mapped by header
+            if !is_vec && !is_remainder {
+                column_count = column_count.max(first_col + type_len);
+                read_cols
+                    .extend((first_col..first_col + type_len).map(|col| quote! { Some(#col) }));
+            }
+            (quote! {}, quote! { #first_col })
+        } else {
+            let first_col = col;
+            match &field.ident {
+                Some(ident) if !column_names_end && !is_vec && !is_remainder && type_len == 1 => {
+                    column_names.push(syn::ext::IdentExt::unraw(ident).to_string());
+                }
+                _ => column_names_end = true,
+            }
+            // `Vec` member may also be empty, so it doesn't require any columns
+            if !is_vec && !is_remainder {
+                col += type_len;
+                column_count = column_count.max(col);
+            }
+            if shifted {
+                (quote! {}, quote! { #first_col + shift })
+            } else {
+                (quote! {}, quote! { #first_col })
             }
         };
 
-        if extern_type_is(ty, "Option") {
-            let inner_type = extract_generic_type(ty)?;
-            single_element = quote! {
-                {
-                    let val = row.pop().unwrap();
-                    match #inner_type::from_xlsx_data(val.clone()) {
-                        Err(epics_gen::ParseErrorKind::ValueMissing) => None,
-                        v => Some(v.map_err(|kind| epics_gen::ParseError::new_in_table(kind,epics_gen::XlsxCell::new((row_num as u32, #i as u32), val), table_name.to_owned()))?),
+        let validate = match &field_props.validate {
+            Some((_, val)) => Some(val.parse::<proc_macro2::TokenStream>()?),
+            None => None,
+        };
+        let is_option = extern_type_is(ty, "Option");
+        if is_option {
+            if let Some((kw, _)) = &field_props.default {
+                return Err(syn::Error::new_spanned(
+                    kw,
+                    "`default` cannot be used on an `Option` member, empty cells are parsed as `None`",
+                ));
+            }
+        }
+
+        let single_element =
+            |col: proc_macro2::TokenStream| -> syn::Result<proc_macro2::TokenStream> {
+                let error = quote! {
+                    |kind| epics_gen::ParseError::new_in_table(kind,epics_gen::XlsxCell::new((row_num as u32, (#col) as u32), cell.clone()), table_name.to_owned())
+                };
+                // `as_string` coerces numeric cells to strings instead of `FromXlsxData`
+                let from_data = |ty: &syn::Type| {
+                    if field_props.as_string {
+                        quote! {
+                            epics_gen::get_as_string(&cell)
+                                .ok_or(epics_gen::ParseErrorKind::ValueMissing)
+                        }
+                    } else {
+                        quote! { #ty::from_xlsx_data(cell.clone()) }
                     }
-                }
+                };
+                let convert = if is_option {
+                    let inner_type = from_data(extract_generic_type(ty)?);
+                    quote! {
+                        match #inner_type {
+                            Err(epics_gen::ParseErrorKind::ValueMissing) => None,
+                            v => Some(v.map_err(#error)?),
+                        }
+                    }
+                } else if let Some(default) = &default {
+                    let from_data = from_data(ty);
+                    quote! {
+                        match #from_data {
+                            Err(epics_gen::ParseErrorKind::ValueMissing) => #default,
+                            v => v.map_err(#error)?,
+                        }
+                    }
+                } else {
+                    let from_data = from_data(ty);
+                    quote! {
+                        #from_data.map_err(#error)?
+                    }
+                };
+                // Handle `validate` attribute, the converted value is bound to `val`
+                let validate = match &validate {
+                    Some(validate) if is_option => quote! {
+                        let val = match val {
+                            Some(val) => {
+                                if !(#validate) {
+                                    return Err((#error)(epics_gen::ParseErrorKind::InvalidValue));
+                                }
+                                Some(val)
+                            }
+                            None => None,
+                        };
+                    },
+                    Some(validate) => quote! {
+                        if !(#validate) {
+                            return Err((#error)(epics_gen::ParseErrorKind::InvalidValue));
+                        }
+                    },
+                    None => quote! {},
+                };
+                Ok(quote! {
+                    {
+                        // missing cells (short rows) are handled the same as empty cells
+                        let cell = row.get(#col).cloned().unwrap_or(epics_gen::XlsxData::Empty);
+                        let val = #convert;
+                        #validate
+                        val
+                    }
+                })
             };
-            inner_type
-        } else {
-            ty
-        };
 
-        let field_output = if type_len > 1 {
+        let field_output = if is_remainder {
+            let read_count = read_cols.len();
+            quote! {
+                {
+                    #col_lookup
+                    let read_cols: [Option<usize>; #read_count] = [#(#read_cols),*];
+                    let read_cols: Vec<usize> = read_cols.into_iter().flatten().collect();
+                    epics_gen::row_remainder(&row, header, #first_col, &read_cols)
+                }
+            }
+        } else if is_vec {
+            let single_element = single_element(quote! { col })?;
+            quote! {
+                {
+                    #col_lookup
+                    // rows are padded to the table width with empty cells, which are not
+                    // elements of the vector
+                    let last_col = row
+                        .iter()
+                        .rposition(|cell| !matches!(cell, epics_gen::XlsxData::Empty))
+                        .map_or(0, |col| col + 1);
+                    let mut elements = Vec::new();
+                    for col in (#first_col)..last_col {
+                        elements.push(#single_element);
+                    }
+                    elements
+                }
+            }
+        } else if type_len > 1 {
             let mut elements = Vec::new();
-            for _ in 0..type_len {
-                elements.push(single_element.clone());
+            for i in 0..type_len {
+                elements.push(single_element(quote! { #first_col + #i })?);
             }
             quote! {
-                #id: [
-                    #(#elements,)*
-                ]
+                {
+                    #col_lookup
+                    [
+                        #(#elements,)*
+                    ]
+                }
             }
         } else {
+            let single_element = single_element(first_col)?;
             quote! {
-                #id: {
+                {
+                    #col_lookup
                     #single_element
                 }
             }
@@ -113,49 +476,314 @@ pub(super) fn impl_derive_xlsx_row(
 
         field_convert.push(field_output);
     }
+    // Named members are assigned by name, tuple struct members by position
+    let construct = if fields.is_empty() {
+        quote! { Self }
+    } else if fields.iter().all(|field| field.ident.is_some()) {
+        let idents = fields.iter().map(|field| &field.ident);
+        quote! {
+            Self {
+                #(#idents: #field_convert,)*
+            }
+        }
+    } else {
+        quote! {
+            Self(
+                #(#field_convert,)*
+            )
+        }
+    };
+
+    // Positional and indexed members need at least `COLUMN_COUNT` cells in the row
+    let column_count_check = if column_count > 0 {
+        quote! {
+            if row.len() < Self::COLUMN_COUNT {
+                return Err(epics_gen::ParseError::new_in_table(
+                    epics_gen::ParseErrorKind::ColumnCountMismatch,
+                    epics_gen::XlsxCell::new((row_num as u32, row.len() as u32), epics_gen::XlsxData::Empty),
+                    table_name.to_owned(),
+                ));
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    let shift = if shifted {
+        quote! { let mut shift: usize = 0; }
+    } else {
+        quote! {}
+    };
+
     let res = quote! {
         impl epics_gen::FromXlsxRow for #id
-        where Self: Sized {
+        where Self: Sized, #(#where_predicates,)* {
+            const COLUMN_COUNT: usize = #column_count;
+            const COLUMN_NAMES: &'static [&'static str] = &[#(#column_names),*];
+
             fn from_xlsx_row(row: epics_gen::XlsxRow, row_num: usize, table_name:&str)
             -> ::std::result::Result<Self, epics_gen::ParseError> {
-                let mut row = row.clone();
-                row.reverse();
-                Ok(Self {
-                    #(#field_convert,)*
-                })
+                Self::from_xlsx_row_with_header(row, &[], row_num, table_name)
             }
 
+            #[allow(unused_variables, unused_assignments)]
+            fn from_xlsx_row_with_header(
+                row: epics_gen::XlsxRow,
+                header: &[String],
+                row_num: usize,
+                table_name: &str,
+            ) -> ::std::result::Result<Self, epics_gen::ParseError> {
+                #column_count_check
+                #shift
+                Ok(#construct)
+            }
         }
     };
     Ok(res)
 }
 
-fn destructure_array(ty: &syn::Type) -> syn::Result<(syn::LitInt, syn::Ident)> {
-    if let syn::Type::Array(syn::TypeArray { elem, len, .. }) = ty {
-        if let syn::Expr::Lit(syn::ExprLit { lit, .. }) = len {
-            let lit_int = if let syn::Lit::Int(lit_int) = lit {
-                lit_int
-            } else {
-                return Err(syn::Error::new_spanned(lit, "Len is not an integer"));
-            };
+mod kw {
+    use syn::custom_keyword;
 
-            let path = match ungroup(elem) {
-                syn::Type::Path(ty) => &ty.path,
-                _ => return Err(syn::Error::new_spanned(elem, "Not a path")),
-            };
-            let seg = match path.segments.last() {
-                Some(seg) => seg,
-                None => return Err(syn::Error::new_spanned(path, "No segments in path")),
-            };
-            Ok((lit_int.clone(), seg.ident.clone()))
+    custom_keyword!(skip);
+    custom_keyword!(default);
+    custom_keyword!(column);
+    custom_keyword!(rename);
+    custom_keyword!(validate);
+    custom_keyword!(as_string);
+    custom_keyword!(parse_with);
+}
+
+/// Attributes of a struct member
+#[derive(Debug, Clone, Default)]
+struct FieldProps {
+    /// member is not parsed from the row, `Default::default()` is used instead
+    pub skip: bool,
+    /// expression used when the cell is empty (or when the member is skipped)
+    pub default: Option<(kw::default, syn::LitStr)>,
+    /// header name or zero-based index of the column the member is read from
+    pub column: Option<(kw::column, ColumnRef)>,
+    /// header name of the member's column, if it differs from the member name
+    pub rename: Option<(kw::rename, syn::LitStr)>,
+    /// predicate the converted value (bound to `val`) needs to satisfy
+    pub validate: Option<(kw::validate, syn::LitStr)>,
+    /// `String` member is read with `get_as_string`, which also accepts numeric cells
+    pub as_string: bool,
+    /// path of the function converting the member from the remaining cells of the row
+    pub parse_with: Option<(kw::parse_with, syn::LitStr)>,
+}
+
+impl FieldProps {
+    fn new(attrs: &[syn::Attribute]) -> syn::Result<Self> {
+        let mut field_props = Self::default();
+        for meta in get_metadata_inner::<FieldMeta>("xlsx", attrs)? {
+            match meta {
+                FieldMeta::Skip { kw } => {
+                    if field_props.skip {
+                        return Err(syn::Error::new_spanned(
+                            kw,
+                            "Found multiple occurrences of xlsx(skip)",
+                        ));
+                    }
+                    field_props.skip = true;
+                }
+                FieldMeta::AsString { kw } => {
+                    if field_props.as_string {
+                        return Err(syn::Error::new_spanned(
+                            kw,
+                            "Found multiple occurrences of xlsx(as_string)",
+                        ));
+                    }
+                    field_props.as_string = true;
+                }
+                FieldMeta::Default { kw, val } => {
+                    if let Some((fst_kw, _)) = field_props.default {
+                        return Err(occurrence_error(fst_kw, kw, "default"));
+                    }
+                    field_props.default = Some((kw, val));
+                }
+                FieldMeta::Column { kw, val } => {
+                    if let Some((fst_kw, _)) = field_props.column {
+                        return Err(occurrence_error(fst_kw, kw, "column"));
+                    }
+                    field_props.column = Some((kw, val));
+                }
+                FieldMeta::Rename { kw, val } => {
+                    if let Some((fst_kw, _)) = field_props.rename {
+                        return Err(occurrence_error(fst_kw, kw, "rename"));
+                    }
+                    field_props.rename = Some((kw, val));
+                }
+                FieldMeta::Validate { kw, val } => {
+                    if let Some((fst_kw, _)) = field_props.validate {
+                        return Err(occurrence_error(fst_kw, kw, "validate"));
+                    }
+                    field_props.validate = Some((kw, val));
+                }
+                FieldMeta::ParseWith { kw, val } => {
+                    if let Some((fst_kw, _)) = field_props.parse_with {
+                        return Err(occurrence_error(fst_kw, kw, "parse_with"));
+                    }
+                    field_props.parse_with = Some((kw, val));
+                }
+            }
+        }
+        Ok(field_props)
+    }
+
+    /// Header name used to look up the member's column. `None` if the member is read by position.
+    fn header_name(&self) -> syn::Result<Option<&syn::LitStr>> {
+        match (&self.column, &self.rename) {
+            (Some((kw1, _)), Some((kw2, _))) => {
+                let mut err =
+                    syn::Error::new_spanned(kw2, "`rename` cannot be used together with `column`");
+                err.combine(syn::Error::new_spanned(kw1, "`column` defined here"));
+                Err(err)
+            }
+            (Some((_, ColumnRef::Header(val))), None) => Ok(Some(val)),
+            (None, Some((_, val))) => Ok(Some(val)),
+            (Some((_, ColumnRef::Index(_))), None) | (None, None) => Ok(None),
+        }
+    }
+
+    /// Zero-based index of the member's column, set by `#[xlsx(column = <index>)]`.
+    fn column_index(&self) -> syn::Result<Option<usize>> {
+        match &self.column {
+            Some((_, ColumnRef::Index(val))) => Ok(Some(val.base10_parse()?)),
+            _ => Ok(None),
+        }
+    }
+}
+
+/// Value of the `column` attribute
+#[derive(Debug, Clone)]
+enum ColumnRef {
+    /// header name of the column
+    Header(syn::LitStr),
+    /// zero-based index of the column
+    Index(syn::LitInt),
+}
+
+impl Parse for ColumnRef {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let lookahead = input.lookahead1();
+        if lookahead.peek(syn::LitStr) {
+            Ok(ColumnRef::Header(input.parse()?))
+        } else if lookahead.peek(syn::LitInt) {
+            Ok(ColumnRef::Index(input.parse()?))
         } else {
-            Err(syn::Error::new_spanned(len, "Len is not an literal!"))
+            Err(lookahead.error())
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+enum FieldMeta {
+    Skip {
+        kw: kw::skip,
+    },
+    AsString {
+        kw: kw::as_string,
+    },
+    Default {
+        kw: kw::default,
+        val: syn::LitStr,
+    },
+    Column {
+        kw: kw::column,
+        val: ColumnRef,
+    },
+    Rename {
+        kw: kw::rename,
+        val: syn::LitStr,
+    },
+    Validate {
+        kw: kw::validate,
+        val: syn::LitStr,
+    },
+    ParseWith {
+        kw: kw::parse_with,
+        val: syn::LitStr,
+    },
+}
+
+impl Parse for FieldMeta {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let lookahead = input.lookahead1();
+        if lookahead.peek(kw::skip) {
+            let kw = input.parse()?;
+            Ok(FieldMeta::Skip { kw })
+        } else if lookahead.peek(kw::as_string) {
+            let kw = input.parse()?;
+            Ok(FieldMeta::AsString { kw })
+        } else if lookahead.peek(kw::default) {
+            let kw = input.parse()?;
+            let _: Token![=] = input.parse()?;
+            let val = input.parse()?;
+            Ok(FieldMeta::Default { kw, val })
+        } else if lookahead.peek(kw::column) {
+            let kw = input.parse()?;
+            let _: Token![=] = input.parse()?;
+            let val = input.parse()?;
+            Ok(FieldMeta::Column { kw, val })
+        } else if lookahead.peek(kw::rename) {
+            let kw = input.parse()?;
+            let _: Token![=] = input.parse()?;
+            let val = input.parse()?;
+            Ok(FieldMeta::Rename { kw, val })
+        } else if lookahead.peek(kw::validate) {
+            let kw = input.parse()?;
+            let _: Token![=] = input.parse()?;
+            let val = input.parse()?;
+            Ok(FieldMeta::Validate { kw, val })
+        } else if lookahead.peek(kw::parse_with) {
+            let kw = input.parse()?;
+            let _: Token![=] = input.parse()?;
+            let val = input.parse()?;
+            Ok(FieldMeta::ParseWith { kw, val })
+        } else {
+            Err(lookahead.error())
         }
-    } else {
-        Err(syn::Error::new_spanned(ty, "Type is not an array!"))
     }
 }
 
+fn destructure_array(ty: &syn::Type) -> syn::Result<(syn::LitInt, syn::Ident)> {
+    let syn::Type::Array(syn::TypeArray { elem, len, .. }) = ty else {
+        return Err(syn::Error::new_spanned(ty, "Type is not an array!"));
+    };
+    let mut len = len;
+    // literals passed through `macro_rules!` are wrapped in an invisible group
+    while let syn::Expr::Group(group) = len {
+        len = &group.expr;
+    }
+    let lit_int = match len {
+        syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Int(lit_int),
+            ..
+        }) => lit_int,
+        syn::Expr::Lit(syn::ExprLit { lit, .. }) => {
+            return Err(syn::Error::new_spanned(lit, "Len is not an integer"));
+        }
+        // The macro needs the number of cells, which isn't known for any other expression
+        _ => {
+            return Err(syn::Error::new_spanned(
+                len,
+                "expected an integer literal as the array length in FromXlsxRow",
+            ));
+        }
+    };
+
+    let path = match ungroup(elem) {
+        syn::Type::Path(ty) => &ty.path,
+        _ => return Err(syn::Error::new_spanned(elem, "Not a path")),
+    };
+    let seg = match path.segments.last() {
+        Some(seg) => seg,
+        None => return Err(syn::Error::new_spanned(path, "No segments in path")),
+    };
+    Ok((lit_int.clone(), seg.ident.clone()))
+}
+
 // If member type is vector or array check for attribute named `len`. This tells the macro how many
 // elements are deserialized into the structure. If no `len` macro is used, just push all elements
 // until the row ends.
@@ -186,6 +814,36 @@ fn extern_type_is<'a>(ty: &'a syn::Type, pattern: &'a str) -> bool {
     }
 }
 
+/// Checks for the `XlsxRowRemainder` type, or the `HashMap<String, XlsxData>` it stands for.
+fn type_is_remainder(ty: &syn::Type) -> bool {
+    let path = match ungroup(ty) {
+        syn::Type::Path(ty) => &ty.path,
+        _ => return false,
+    };
+    let Some(seg) = path.segments.last() else {
+        return false;
+    };
+    match &seg.arguments {
+        syn::PathArguments::None => seg.ident == "XlsxRowRemainder",
+        // the alias spelled out, `HashMap<String, XlsxData>`
+        syn::PathArguments::AngleBracketed(bracketed) => {
+            let arg_is = |arg: &syn::GenericArgument, name: &str| match arg {
+                syn::GenericArgument::Type(syn::Type::Path(ty)) => ty
+                    .path
+                    .segments
+                    .last()
+                    .is_some_and(|seg| seg.ident == name && seg.arguments.is_none()),
+                _ => false,
+            };
+            seg.ident == "HashMap"
+                && bracketed.args.len() == 2
+                && arg_is(&bracketed.args[0], "String")
+                && arg_is(&bracketed.args[1], "XlsxData")
+        }
+        _ => false,
+    }
+}
+
 fn extract_generic_type(ty: &syn::Type) -> syn::Result<&syn::Type> {
     let path = match ungroup(ty) {
         syn::Type::Path(ty) => &ty.path,